@@ -42,7 +42,7 @@ async fn main() {
     // Time zone (default to UTC)
     opts.time_zone = Some(chrono_tz::Europe::Paris.name().into());
 
-    // Past days (0-2)
+    // Past days (0-92)
     // opts.past_days = Some(2); // !! mutually exclusive with dates
 
     // Forecast days (0-16)
@@ -67,6 +67,10 @@ async fn main() {
     opts.cell_selection = Some("sea".try_into().unwrap()); // or
     opts.cell_selection = Some("nearest".try_into().unwrap());
 
+    // Solar panel orientation (for `global_tilted_irradiance`)
+    opts.tilt = Some(30.0.try_into().unwrap()); // 0 to 90
+    opts.azimuth = Some(0.0.try_into().unwrap()); // -180 to 180, 0 is south
+
     // Current weather
     opts.current.push("temperature_2m".into());
     opts.current.push("is_day".into());
@@ -0,0 +1,451 @@
+//! Degree days and agronomic indices computed from daily and hourly records.
+//!
+//! Indices are usually computed over a season that started in the past, splice the archive
+//! history onto the forecast first:
+//!
+//! ```no_run
+//! # async fn run(client: open_meteo_rs::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! use open_meteo_rs::agro::{self, DegreeDays, Method};
+//! use open_meteo_rs::forecast::Options;
+//!
+//! # let (archive_opts, forecast_opts) = (Options::default(), Options::default());
+//! let archive = client.archive(archive_opts).await?;
+//! let forecast = client.forecast(forecast_opts).await?;
+//!
+//! let daily = agro::splice_daily(
+//!     archive.daily.as_deref().unwrap_or_default(),
+//!     forecast.daily.as_deref().unwrap_or_default(),
+//! );
+//!
+//! let gdd = DegreeDays::new(10.0)
+//!     .with_upper(30.0)
+//!     .with_method(Method::SingleSine)
+//!     .growing(&daily);
+//! let season_total = agro::cumulative(&gdd);
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::forecast::{
+    ForecastResultDaily, ForecastResultHourly, PrecipitationUnit, TemperatureUnit,
+};
+use crate::series::Series;
+use crate::timeline::{self, Precedence};
+use crate::units::Unit;
+use std::f64::consts::{FRAC_PI_2, PI};
+
+const TEMPERATURE_MAX: &str = "temperature_2m_max";
+const TEMPERATURE_MIN: &str = "temperature_2m_min";
+const TEMPERATURE_MEAN: &str = "temperature_2m_mean";
+const TEMPERATURE: &str = "temperature_2m";
+const PRECIPITATION_SUM: &str = "precipitation_sum";
+const ET0: &str = "et0_fao_evapotranspiration";
+const CELSIUS: Unit = Unit::Temperature(TemperatureUnit::Celsius);
+const MILLIMETERS: Unit = Unit::Precipitation(PrecipitationUnit::Millimeters);
+
+/// Method used to integrate temperatures above the base temperature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Method {
+    /// Mean of the maximum, capped at the upper threshold, and the minimum
+    #[default]
+    SimpleAverage,
+    /// Sine curve fitted through the minimum and maximum of the day
+    SingleSine,
+    /// Two triangles per day, the second one uses the minimum of the following day
+    DoubleTriangle,
+}
+
+/// Growing degree days configuration, temperatures are in °C whatever the unit of the response
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DegreeDays {
+    pub base: f64,
+    /// Horizontal cutoff, temperatures above it do not contribute
+    pub upper: Option<f64>,
+    pub method: Method,
+}
+
+impl DegreeDays {
+    #[must_use]
+    pub fn new(base: f64) -> Self {
+        Self {
+            base,
+            upper: None,
+            method: Method::default(),
+        }
+    }
+
+    #[must_use]
+    pub fn with_upper(mut self, upper: f64) -> Self {
+        self.upper = Some(upper);
+        self
+    }
+
+    #[must_use]
+    pub fn with_method(mut self, method: Method) -> Self {
+        self.method = method;
+        self
+    }
+
+    /// Degree days of a single day. `next_min` is only used by the double triangle method and
+    /// defaults to `min`.
+    #[must_use]
+    pub fn day(&self, min: f64, max: f64, next_min: Option<f64>) -> f64 {
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        let upper = self.upper.unwrap_or(f64::INFINITY);
+
+        match self.method {
+            Method::SimpleAverage => (f64::midpoint(max.min(upper), min) - self.base).max(0.0),
+            Method::SingleSine => single_sine(min, max, self.base, upper),
+            Method::DoubleTriangle => {
+                let next_min = next_min.unwrap_or(min).min(max);
+                f64::midpoint(
+                    triangle(min, max, self.base, upper),
+                    triangle(next_min, max, self.base, upper),
+                )
+            }
+        }
+    }
+
+    /// Daily growing degree days from `temperature_2m_min` and `temperature_2m_max`
+    #[must_use]
+    pub fn growing(&self, daily: &[ForecastResultDaily]) -> Series {
+        let min = celsius(daily, TEMPERATURE_MIN);
+        let max = celsius(daily, TEMPERATURE_MAX);
+        let values = (0..daily.len())
+            .map(|idx| {
+                let next_min = min.get(idx + 1).copied().flatten();
+                Some(self.day(min[idx]?, max[idx]?, next_min))
+            })
+            .collect();
+
+        Series::new("growing_degree_days", Some(CELSIUS.to_string()), values)
+    }
+}
+
+fn single_sine(min: f64, max: f64, base: f64, upper: f64) -> f64 {
+    if max <= base {
+        return 0.0;
+    }
+    if min >= upper {
+        return upper - base;
+    }
+    if min >= base && max <= upper {
+        return f64::midpoint(min, max) - base;
+    }
+
+    let mean = f64::midpoint(min, max);
+    let amplitude = (max - min) / 2.0;
+    let theta1 = if min < base {
+        ((base - mean) / amplitude).asin()
+    } else {
+        -FRAC_PI_2
+    };
+    let (theta2, cutoff) = if max > upper {
+        let theta2 = ((upper - mean) / amplitude).asin();
+        (theta2, (upper - base) * (FRAC_PI_2 - theta2))
+    } else {
+        (FRAC_PI_2, 0.0)
+    };
+
+    ((mean - base) * (theta2 - theta1) + amplitude * (theta1.cos() - theta2.cos()) + cutoff) / PI
+}
+
+fn triangle(min: f64, max: f64, base: f64, upper: f64) -> f64 {
+    if max <= base {
+        return 0.0;
+    }
+    if min >= upper {
+        return upper - base;
+    }
+
+    let range = max - min;
+    let below = if min < base {
+        (base - min).powi(2) / (2.0 * range)
+    } else {
+        0.0
+    };
+    let above = if max > upper {
+        (max - upper).powi(2) / (2.0 * range)
+    } else {
+        0.0
+    };
+
+    f64::midpoint(min, max) - base + below - above
+}
+
+/// Values of the daily variable `name` in °C, missing if it is absent or not a temperature
+fn celsius(daily: &[ForecastResultDaily], name: &str) -> Vec<Option<f64>> {
+    Series::from_daily_in(daily, name, CELSIUS)
+        .map_or_else(|| vec![None; daily.len()], |series| series.values)
+}
+
+/// Values of the daily variable `name` in mm, missing if it is absent or not a length
+fn millimeters(daily: &[ForecastResultDaily], name: &str) -> Vec<Option<f64>> {
+    Series::from_daily_in(daily, name, MILLIMETERS)
+        .map_or_else(|| vec![None; daily.len()], |series| series.values)
+}
+
+/// `temperature_2m_mean` or the mean of the minimum and maximum, in °C
+fn daily_mean(daily: &[ForecastResultDaily]) -> Vec<Option<f64>> {
+    let min = celsius(daily, TEMPERATURE_MIN);
+    let max = celsius(daily, TEMPERATURE_MAX);
+    celsius(daily, TEMPERATURE_MEAN)
+        .into_iter()
+        .zip(min.into_iter().zip(max))
+        .map(|(mean, (min, max))| mean.or_else(|| Some(f64::midpoint(min?, max?))))
+        .collect()
+}
+
+/// Daily heating degree days below `base` °C, from `temperature_2m_mean` or the mean of the
+/// minimum and maximum
+#[must_use]
+pub fn heating_degree_days(daily: &[ForecastResultDaily], base: f64) -> Series {
+    let values = daily_mean(daily)
+        .into_iter()
+        .map(|mean| Some((base - mean?).max(0.0)))
+        .collect();
+    Series::new("heating_degree_days", Some(CELSIUS.to_string()), values)
+}
+
+/// Daily cooling degree days above `base` °C, from `temperature_2m_mean` or the mean of the
+/// minimum and maximum
+#[must_use]
+pub fn cooling_degree_days(daily: &[ForecastResultDaily], base: f64) -> Series {
+    let values = daily_mean(daily)
+        .into_iter()
+        .map(|mean| Some((mean? - base).max(0.0)))
+        .collect();
+    Series::new("cooling_degree_days", Some(CELSIUS.to_string()), values)
+}
+
+/// Number of hourly records with `temperature_2m` within `low..=high` °C, usually 0 to 7.2 °C
+#[must_use]
+pub fn chilling_hours(hourly: &[ForecastResultHourly], low: f64, high: f64) -> usize {
+    Series::from_hourly_in(hourly, TEMPERATURE, CELSIUS).map_or(0, |series| {
+        series
+            .values
+            .into_iter()
+            .flatten()
+            .filter(|t| (low..=high).contains(t))
+            .count()
+    })
+}
+
+/// Number of days with `temperature_2m_min` below 0 °C
+#[must_use]
+pub fn frost_days(daily: &[ForecastResultDaily]) -> usize {
+    celsius(daily, TEMPERATURE_MIN)
+        .into_iter()
+        .flatten()
+        .filter(|t| *t < 0.0)
+        .count()
+}
+
+/// Cumulative `precipitation_sum` minus `et0_fao_evapotranspiration`, converted to mm
+#[must_use]
+pub fn precipitation_balance(daily: &[ForecastResultDaily]) -> Series {
+    let values = millimeters(daily, PRECIPITATION_SUM)
+        .into_iter()
+        .zip(millimeters(daily, ET0))
+        .map(|(precipitation, et0)| Some(precipitation? - et0?))
+        .collect();
+    cumulative(&Series::new(
+        "precipitation_balance",
+        Some(MILLIMETERS.to_string()),
+        values,
+    ))
+}
+
+/// Running total of a series, missing values are skipped and stay missing
+#[must_use]
+pub fn cumulative(series: &Series) -> Series {
+    let mut total = 0.0;
+    let values = series
+        .values
+        .iter()
+        .map(|v| {
+            total += (*v)?;
+            Some(total)
+        })
+        .collect();
+    Series::new(series.name.clone(), series.unit.clone(), values)
+}
+
+/// Archive records completed with the forecast, see [`timeline::merge`]. Archived days
+/// without any value, such as the last days before the archive is updated, are replaced by the
+/// forecast.
+#[must_use]
+pub fn splice_daily(
+    archive: &[ForecastResultDaily],
+    forecast: &[ForecastResultDaily],
+) -> Vec<ForecastResultDaily> {
+    timeline::merge_by(
+        archive.to_vec(),
+        forecast.to_vec(),
+        Precedence::Archive,
+        |rec| rec.date,
+        |rec| &rec.values,
+    )
+    .into_iter()
+    .map(|(_, rec)| rec)
+    .collect()
+}
+
+/// Archive records completed with the forecast, see [`timeline::merge`]
+#[must_use]
+pub fn splice_hourly(
+    archive: &[ForecastResultHourly],
+    forecast: &[ForecastResultHourly],
+) -> Vec<ForecastResultHourly> {
+    timeline::merge(archive.to_vec(), forecast.to_vec(), Precedence::Archive)
+        .into_iter()
+        .map(|rec| rec.record)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    fn day(offset: i64, unit: &str, values: &[(&str, Option<f64>)]) -> ForecastResultDaily {
+        ForecastResultDaily {
+            date: chrono::NaiveDate::from_ymd_opt(2024, 5, 1).unwrap()
+                + chrono::Duration::days(offset),
+            values: values
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        (*k).to_string(),
+                        ForecastResultItem {
+                            unit: Some(unit.to_string()),
+                            value: v.map_or(serde_json::Value::Null, Into::into),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn min_max(offset: i64, min: f64, max: f64) -> ForecastResultDaily {
+        day(
+            offset,
+            "°C",
+            &[(TEMPERATURE_MIN, Some(min)), (TEMPERATURE_MAX, Some(max))],
+        )
+    }
+
+    #[test]
+    fn degree_day_methods() {
+        let gdd = DegreeDays::new(10.0);
+        assert!((gdd.day(12.0, 24.0, None) - 8.0).abs() < 1e-9);
+        assert!(gdd.day(2.0, 9.0, None).abs() < 1e-9);
+
+        // Fully above the base temperature, every method matches the average
+        for method in [Method::SingleSine, Method::DoubleTriangle] {
+            let value = gdd.with_method(method).day(12.0, 24.0, Some(12.0));
+            assert!((value - 8.0).abs() < 1e-9);
+        }
+
+        // Symmetric around the base temperature
+        let sine = gdd.with_method(Method::SingleSine).day(0.0, 20.0, None);
+        assert!((sine - 10.0 / PI).abs() < 1e-9);
+        let triangle = gdd.with_method(Method::DoubleTriangle).day(0.0, 20.0, None);
+        assert!((triangle - 2.5).abs() < 1e-9);
+
+        let capped = gdd.with_upper(20.0).day(10.0, 30.0, None);
+        assert!((capped - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn indices_over_spliced_records() {
+        let archive = vec![
+            min_max(0, -2.0, 8.0),
+            min_max(1, 4.0, 20.0),
+            // Not archived yet
+            day(2, "°C", &[(TEMPERATURE_MIN, None), (TEMPERATURE_MAX, None)]),
+        ];
+        let forecast = vec![min_max(1, 0.0, 0.0), min_max(2, 10.0, 30.0)];
+
+        let daily = splice_daily(&archive, &forecast);
+        assert_eq!(daily.len(), 3);
+        assert_eq!(daily[1].value_f64(TEMPERATURE_MAX), Some(20.0));
+        assert_eq!(daily[2].value_f64(TEMPERATURE_MAX), Some(30.0));
+
+        assert_eq!(frost_days(&daily), 1);
+        assert_eq!(
+            heating_degree_days(&daily, 18.0).values,
+            vec![Some(15.0), Some(6.0), Some(0.0)]
+        );
+        assert_eq!(
+            cooling_degree_days(&daily, 18.0).values,
+            vec![Some(0.0), Some(0.0), Some(2.0)]
+        );
+        assert_eq!(
+            cumulative(&DegreeDays::new(10.0).growing(&daily)).values,
+            vec![Some(0.0), Some(2.0), Some(12.0)]
+        );
+    }
+
+    #[test]
+    fn indices_in_fahrenheit() {
+        let daily = vec![
+            day(
+                0,
+                "°F",
+                &[(TEMPERATURE_MIN, Some(30.2)), (TEMPERATURE_MAX, Some(46.4))],
+            ),
+            day(
+                1,
+                "°F",
+                &[(TEMPERATURE_MIN, Some(50.0)), (TEMPERATURE_MAX, Some(86.0))],
+            ),
+        ];
+
+        assert_eq!(frost_days(&daily), 1);
+        let hdd = heating_degree_days(&daily, 18.0);
+        assert_eq!(hdd.unit.as_deref(), Some("°C"));
+        assert!((hdd.values[0].unwrap() - 14.5).abs() < 1e-9);
+        let gdd = DegreeDays::new(10.0).growing(&daily);
+        assert!((gdd.values[1].unwrap() - 10.0).abs() < 1e-9);
+
+        let hourly: Vec<_> = [33.8, 41.0, 50.0]
+            .into_iter()
+            .map(|t| ForecastResultHourly {
+                values: [(
+                    TEMPERATURE.to_string(),
+                    ForecastResultItem {
+                        unit: Some("°F".into()),
+                        value: t.into(),
+                    },
+                )]
+                .into(),
+                ..Default::default()
+            })
+            .collect();
+        assert_eq!(chilling_hours(&hourly, 0.0, 7.2), 2);
+    }
+
+    #[test]
+    fn precipitation_balance_in_inches() {
+        let daily = vec![
+            day(
+                0,
+                "inch",
+                &[(PRECIPITATION_SUM, Some(0.5)), (ET0, Some(0.1))],
+            ),
+            day(1, "inch", &[(PRECIPITATION_SUM, None), (ET0, Some(0.2))]),
+            day(
+                2,
+                "inch",
+                &[(PRECIPITATION_SUM, Some(0.0)), (ET0, Some(0.2))],
+            ),
+        ];
+
+        let balance = precipitation_balance(&daily);
+        assert_eq!(balance.unit.as_deref(), Some("mm"));
+        assert!((balance.values[0].unwrap() - 10.16).abs() < 1e-9);
+        assert_eq!(balance.values[1], None);
+        assert!((balance.values[2].unwrap() - 5.08).abs() < 1e-9);
+    }
+}
@@ -3,19 +3,346 @@ use std::error::Error;
 
 use crate::forecast::{
     extract_times, unix_time_to_naive_datetime, CellSelection, CurrentResult, ForecastResultHourly,
-    ForecastResultItem,
+    ForecastResultItem, Metadata,
 };
-use crate::{client, errors, location};
+use crate::{builder, client, errors, location, query, validation};
 use serde::{Deserialize, Serialize};
+use std::fmt::Display;
 
-#[derive(Debug, Clone, Default)]
+/// Air quality variable
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[non_exhaustive]
+pub enum Pollutant {
+    /// Particulate matter with diameter below 10 µm
+    Pm10,
+    /// Particulate matter with diameter below 2.5 µm
+    Pm2_5,
+    CarbonMonoxide,
+    CarbonDioxide,
+    NitrogenDioxide,
+    NitrogenMonoxide,
+    SulphurDioxide,
+    Ozone,
+    Methane,
+    Ammonia,
+    Formaldehyde,
+    Glyoxal,
+    NonMethaneVolatileOrganicCompounds,
+    PeroxyacylNitrates,
+    /// Aerosol optical depth at 550 nm
+    AerosolOpticalDepth,
+    Dust,
+    UvIndex,
+    UvIndexClearSky,
+    Pm10Wildfires,
+    Pm2_5TotalOrganicMatter,
+    SecondaryInorganicAerosol,
+    ResidentialElementaryCarbon,
+    TotalElementaryCarbon,
+    SeaSaltAerosol,
+    AlderPollen,
+    BirchPollen,
+    GrassPollen,
+    MugwortPollen,
+    OlivePollen,
+    RagweedPollen,
+    /// European Air Quality Index
+    EuropeanAqi,
+    EuropeanAqiPm2_5,
+    EuropeanAqiPm10,
+    EuropeanAqiNitrogenDioxide,
+    EuropeanAqiOzone,
+    EuropeanAqiSulphurDioxide,
+    /// United States Air Quality Index
+    UsAqi,
+    UsAqiPm2_5,
+    UsAqiPm10,
+    UsAqiNitrogenDioxide,
+    UsAqiCarbonMonoxide,
+    UsAqiOzone,
+    UsAqiSulphurDioxide,
+    /// Variable not known by this crate
+    Other(String),
+}
+
+impl Display for Pollutant {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pm10 => write!(f, "pm10"),
+            Self::Pm2_5 => write!(f, "pm2_5"),
+            Self::CarbonMonoxide => write!(f, "carbon_monoxide"),
+            Self::CarbonDioxide => write!(f, "carbon_dioxide"),
+            Self::NitrogenDioxide => write!(f, "nitrogen_dioxide"),
+            Self::NitrogenMonoxide => write!(f, "nitrogen_monoxide"),
+            Self::SulphurDioxide => write!(f, "sulphur_dioxide"),
+            Self::Ozone => write!(f, "ozone"),
+            Self::Methane => write!(f, "methane"),
+            Self::Ammonia => write!(f, "ammonia"),
+            Self::Formaldehyde => write!(f, "formaldehyde"),
+            Self::Glyoxal => write!(f, "glyoxal"),
+            Self::NonMethaneVolatileOrganicCompounds => {
+                write!(f, "non_methane_volatile_organic_compounds")
+            }
+            Self::PeroxyacylNitrates => write!(f, "peroxyacyl_nitrates"),
+            Self::AerosolOpticalDepth => write!(f, "aerosol_optical_depth"),
+            Self::Dust => write!(f, "dust"),
+            Self::UvIndex => write!(f, "uv_index"),
+            Self::UvIndexClearSky => write!(f, "uv_index_clear_sky"),
+            Self::Pm10Wildfires => write!(f, "pm10_wildfires"),
+            Self::Pm2_5TotalOrganicMatter => write!(f, "pm2_5_total_organic_matter"),
+            Self::SecondaryInorganicAerosol => write!(f, "secondary_inorganic_aerosol"),
+            Self::ResidentialElementaryCarbon => write!(f, "residential_elementary_carbon"),
+            Self::TotalElementaryCarbon => write!(f, "total_elementary_carbon"),
+            Self::SeaSaltAerosol => write!(f, "sea_salt_aerosol"),
+            Self::AlderPollen => write!(f, "alder_pollen"),
+            Self::BirchPollen => write!(f, "birch_pollen"),
+            Self::GrassPollen => write!(f, "grass_pollen"),
+            Self::MugwortPollen => write!(f, "mugwort_pollen"),
+            Self::OlivePollen => write!(f, "olive_pollen"),
+            Self::RagweedPollen => write!(f, "ragweed_pollen"),
+            Self::EuropeanAqi => write!(f, "european_aqi"),
+            Self::EuropeanAqiPm2_5 => write!(f, "european_aqi_pm2_5"),
+            Self::EuropeanAqiPm10 => write!(f, "european_aqi_pm10"),
+            Self::EuropeanAqiNitrogenDioxide => write!(f, "european_aqi_nitrogen_dioxide"),
+            Self::EuropeanAqiOzone => write!(f, "european_aqi_ozone"),
+            Self::EuropeanAqiSulphurDioxide => write!(f, "european_aqi_sulphur_dioxide"),
+            Self::UsAqi => write!(f, "us_aqi"),
+            Self::UsAqiPm2_5 => write!(f, "us_aqi_pm2_5"),
+            Self::UsAqiPm10 => write!(f, "us_aqi_pm10"),
+            Self::UsAqiNitrogenDioxide => write!(f, "us_aqi_nitrogen_dioxide"),
+            Self::UsAqiCarbonMonoxide => write!(f, "us_aqi_carbon_monoxide"),
+            Self::UsAqiOzone => write!(f, "us_aqi_ozone"),
+            Self::UsAqiSulphurDioxide => write!(f, "us_aqi_sulphur_dioxide"),
+            Self::Other(name) => write!(f, "{name}"),
+        }
+    }
+}
+
+impl From<Pollutant> for String {
+    fn from(value: Pollutant) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for Pollutant {
+    type Error = errors::ConversionError;
+
+    #[allow(clippy::too_many_lines)]
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "pm10" => Ok(Self::Pm10),
+            "pm2_5" => Ok(Self::Pm2_5),
+            "carbon_monoxide" => Ok(Self::CarbonMonoxide),
+            "carbon_dioxide" => Ok(Self::CarbonDioxide),
+            "nitrogen_dioxide" => Ok(Self::NitrogenDioxide),
+            "nitrogen_monoxide" => Ok(Self::NitrogenMonoxide),
+            "sulphur_dioxide" => Ok(Self::SulphurDioxide),
+            "ozone" => Ok(Self::Ozone),
+            "methane" => Ok(Self::Methane),
+            "ammonia" => Ok(Self::Ammonia),
+            "formaldehyde" => Ok(Self::Formaldehyde),
+            "glyoxal" => Ok(Self::Glyoxal),
+            "non_methane_volatile_organic_compounds" => {
+                Ok(Self::NonMethaneVolatileOrganicCompounds)
+            }
+            "peroxyacyl_nitrates" => Ok(Self::PeroxyacylNitrates),
+            "aerosol_optical_depth" => Ok(Self::AerosolOpticalDepth),
+            "dust" => Ok(Self::Dust),
+            "uv_index" => Ok(Self::UvIndex),
+            "uv_index_clear_sky" => Ok(Self::UvIndexClearSky),
+            "pm10_wildfires" => Ok(Self::Pm10Wildfires),
+            "pm2_5_total_organic_matter" => Ok(Self::Pm2_5TotalOrganicMatter),
+            "secondary_inorganic_aerosol" => Ok(Self::SecondaryInorganicAerosol),
+            "residential_elementary_carbon" => Ok(Self::ResidentialElementaryCarbon),
+            "total_elementary_carbon" => Ok(Self::TotalElementaryCarbon),
+            "sea_salt_aerosol" => Ok(Self::SeaSaltAerosol),
+            "alder_pollen" => Ok(Self::AlderPollen),
+            "birch_pollen" => Ok(Self::BirchPollen),
+            "grass_pollen" => Ok(Self::GrassPollen),
+            "mugwort_pollen" => Ok(Self::MugwortPollen),
+            "olive_pollen" => Ok(Self::OlivePollen),
+            "ragweed_pollen" => Ok(Self::RagweedPollen),
+            "european_aqi" => Ok(Self::EuropeanAqi),
+            "european_aqi_pm2_5" => Ok(Self::EuropeanAqiPm2_5),
+            "european_aqi_pm10" => Ok(Self::EuropeanAqiPm10),
+            "european_aqi_nitrogen_dioxide" => Ok(Self::EuropeanAqiNitrogenDioxide),
+            "european_aqi_ozone" => Ok(Self::EuropeanAqiOzone),
+            "european_aqi_sulphur_dioxide" => Ok(Self::EuropeanAqiSulphurDioxide),
+            "us_aqi" => Ok(Self::UsAqi),
+            "us_aqi_pm2_5" => Ok(Self::UsAqiPm2_5),
+            "us_aqi_pm10" => Ok(Self::UsAqiPm10),
+            "us_aqi_nitrogen_dioxide" => Ok(Self::UsAqiNitrogenDioxide),
+            "us_aqi_carbon_monoxide" => Ok(Self::UsAqiCarbonMonoxide),
+            "us_aqi_ozone" => Ok(Self::UsAqiOzone),
+            "us_aqi_sulphur_dioxide" => Ok(Self::UsAqiSulphurDioxide),
+            "" => Err(errors::ConversionError::InvalidPollutant {
+                pollutant: value.to_string(),
+            }),
+            _ => Ok(Self::Other(value.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for Pollutant {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Pollen species forecast over Europe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum PollenSpecies {
+    Alder,
+    Birch,
+    Grass,
+    Mugwort,
+    Olive,
+    Ragweed,
+}
+
+impl PollenSpecies {
+    pub const ALL: [PollenSpecies; 6] = [
+        Self::Alder,
+        Self::Birch,
+        Self::Grass,
+        Self::Mugwort,
+        Self::Olive,
+        Self::Ragweed,
+    ];
+
+    /// Variable carrying the concentration of the species
+    #[must_use]
+    pub fn pollutant(self) -> Pollutant {
+        match self {
+            Self::Alder => Pollutant::AlderPollen,
+            Self::Birch => Pollutant::BirchPollen,
+            Self::Grass => Pollutant::GrassPollen,
+            Self::Mugwort => Pollutant::MugwortPollen,
+            Self::Olive => Pollutant::OlivePollen,
+            Self::Ragweed => Pollutant::RagweedPollen,
+        }
+    }
+}
+
+impl Display for PollenSpecies {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Alder => write!(f, "alder"),
+            Self::Birch => write!(f, "birch"),
+            Self::Grass => write!(f, "grass"),
+            Self::Mugwort => write!(f, "mugwort"),
+            Self::Olive => write!(f, "olive"),
+            Self::Ragweed => write!(f, "ragweed"),
+        }
+    }
+}
+
+impl From<PollenSpecies> for String {
+    fn from(value: PollenSpecies) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for PollenSpecies {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "alder" => Ok(Self::Alder),
+            "birch" => Ok(Self::Birch),
+            "grass" => Ok(Self::Grass),
+            "mugwort" => Ok(Self::Mugwort),
+            "olive" => Ok(Self::Olive),
+            "ragweed" => Ok(Self::Ragweed),
+            _ => Err(errors::ConversionError::InvalidPollenSpecies {
+                species: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for PollenSpecies {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+impl Pollutant {
+    /// Species of a pollen variable
+    #[must_use]
+    pub fn pollen_species(&self) -> Option<PollenSpecies> {
+        PollenSpecies::ALL
+            .into_iter()
+            .find(|species| species.pollutant() == *self)
+    }
+}
+
+/// Domain of the air quality models
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum AirQualityDomain {
+    /// CAMS Europe where available, CAMS global elsewhere
+    #[default]
+    Auto,
+    CamsEurope,
+    CamsGlobal,
+}
+
+impl Display for AirQualityDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Auto => write!(f, "auto"),
+            Self::CamsEurope => write!(f, "cams_europe"),
+            Self::CamsGlobal => write!(f, "cams_global"),
+        }
+    }
+}
+
+impl From<AirQualityDomain> for String {
+    fn from(value: AirQualityDomain) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for AirQualityDomain {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "auto" => Ok(Self::Auto),
+            "cams_europe" => Ok(Self::CamsEurope),
+            "cams_global" => Ok(Self::CamsGlobal),
+            _ => Err(errors::ConversionError::InvalidAirQualityDomain {
+                domain: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<String> for AirQualityDomain {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Options {
     pub location: location::Location,
     /// Attributes to request in hourly intervals
-    pub hourly: Vec<String>,
+    pub hourly: Vec<Pollutant>,
     /// Attributes to request for the current values
-    pub current: Vec<String>,
-    pub domains: Option<String>,
+    pub current: Vec<Pollutant>,
+    pub domains: Option<AirQualityDomain>,
     /// Timeformat is always set to unix
     pub time_zone: Option<String>,
     pub past_days: Option<u8>,
@@ -24,11 +351,188 @@ pub struct Options {
     pub past_hours: Option<u32>,
     pub start_date: Option<chrono::NaiveDate>,
     pub end_date: Option<chrono::NaiveDate>,
+    /// First hour to return, in the requested time zone
+    pub start_hour: Option<chrono::NaiveDateTime>,
+    /// Last hour to return (inclusive), in the requested time zone
+    pub end_hour: Option<chrono::NaiveDateTime>,
     pub cell_selection: Option<CellSelection>,
     pub apikey: Option<String>,
 }
 
+/// Maximum value of `forecast_days`
+pub const MAX_FORECAST_DAYS: u8 = 7;
+/// Maximum value of `past_days`
+pub const MAX_PAST_DAYS: u8 = 92;
+/// First date of the CAMS European reanalysis
+pub const FIRST_DATE: chrono::NaiveDate = match chrono::NaiveDate::from_ymd_opt(2013, 1, 1) {
+    Some(date) => date,
+    None => panic!("invalid date"),
+};
+/// Longer date or hour ranges are split into several requests of this number of days
+pub const CHUNK_DAYS: i64 = 92;
+
 impl Options {
+    /// Check the options against the air quality API constraints.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` describing the first invalid parameter.
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        validation::check_location(&self.location)?;
+        validation::check_dates(self.start_date, self.end_date)?;
+        validation::check_hours(("start_hour", self.start_hour), ("end_hour", self.end_hour))?;
+
+        let has_dates = self.start_date.is_some();
+        let has_hours = self.start_hour.is_some();
+        validation::check_exclusive(("start_hour", has_hours), ("start_date", has_dates))?;
+        for (name, is_set) in [
+            ("past_days", self.past_days.is_some()),
+            ("forecast_days", self.forecast_days.is_some()),
+            ("past_hours", self.past_hours.is_some()),
+            ("forecast_hours", self.forecast_hours.is_some()),
+        ] {
+            validation::check_exclusive((name, is_set), ("start_date", has_dates))?;
+            validation::check_exclusive((name, is_set), ("start_hour", has_hours))?;
+        }
+
+        let last_date =
+            chrono::Utc::now().date_naive() + chrono::Duration::days(i64::from(MAX_FORECAST_DAYS));
+        for (name, date) in [
+            ("start_date", self.start_date),
+            ("end_date", self.end_date),
+            ("start_hour", self.start_hour.map(|hour| hour.date())),
+            ("end_hour", self.end_hour.map(|hour| hour.date())),
+        ] {
+            validation::check_optional_range(name, date, FIRST_DATE, last_date)?;
+        }
+
+        validation::check_optional_range("past_days", self.past_days, 0, MAX_PAST_DAYS)?;
+        validation::check_optional_range(
+            "forecast_days",
+            self.forecast_days,
+            0,
+            MAX_FORECAST_DAYS,
+        )?;
+        validation::check_optional_range(
+            "past_hours",
+            self.past_hours,
+            0,
+            u32::from(MAX_PAST_DAYS) * 24,
+        )?;
+        validation::check_optional_range(
+            "forecast_hours",
+            self.forecast_hours,
+            0,
+            u32::from(MAX_FORECAST_DAYS) * 24,
+        )
+    }
+
+    /// Split the date or hour range into consecutive ranges of at most `days` days. Options
+    /// without a range are returned unchanged.
+    #[must_use]
+    pub fn chunks(&self, days: i64) -> Vec<Options> {
+        let days = days.max(1);
+        let mut chunks = Vec::new();
+
+        if let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date) {
+            let mut start = start_date;
+            while start <= end_date {
+                let end = (start + chrono::Duration::days(days - 1)).min(end_date);
+                chunks.push(Options {
+                    start_date: Some(start),
+                    end_date: Some(end),
+                    ..self.clone()
+                });
+                start = end + chrono::Duration::days(1);
+            }
+        } else if let (Some(start_hour), Some(end_hour)) = (self.start_hour, self.end_hour) {
+            let mut start = start_hour;
+            while start <= end_hour {
+                let end = (start + chrono::Duration::hours(days * 24 - 1)).min(end_hour);
+                chunks.push(Options {
+                    start_hour: Some(start),
+                    end_hour: Some(end),
+                    ..self.clone()
+                });
+                start = end + chrono::Duration::hours(1);
+            }
+        }
+
+        if chunks.is_empty() {
+            chunks.push(self.clone());
+        }
+        chunks
+    }
+
+    /// Parse options from an Open-Meteo URL or query string. Parameters not supported by
+    /// [`Options`] are ignored.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if a parameter is invalid or if the location is missing.
+    pub fn from_query_string(input: &str) -> Result<Self, errors::ConversionError> {
+        Ok(Self::from_query_string_with_unknown(input)?.0)
+    }
+
+    /// Same as [`Options::from_query_string`], also returning the parameters that were ignored
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if a parameter is invalid or if the location is missing.
+    pub fn from_query_string_with_unknown(
+        input: &str,
+    ) -> Result<(Self, Vec<(String, String)>), errors::ConversionError> {
+        let mut opts = Self::default();
+        let mut unknown = Vec::new();
+        let mut lat = None;
+        let mut lng = None;
+
+        for (k, v) in query::parse_pairs(input)? {
+            match k.as_str() {
+                "latitude" => lat = Some(query::parse_value(&k, &v)?),
+                "longitude" => lng = Some(query::parse_value(&k, &v)?),
+                "hourly" => opts.hourly = parse_pollutants(&v)?,
+                "current" => opts.current = parse_pollutants(&v)?,
+                "domains" => opts.domains = Some(v.try_into()?),
+                "timezone" => opts.time_zone = Some(v),
+                "past_days" => opts.past_days = Some(query::parse_value(&k, &v)?),
+                "forecast_days" => opts.forecast_days = Some(query::parse_value(&k, &v)?),
+                "forecast_hours" => opts.forecast_hours = Some(query::parse_value(&k, &v)?),
+                "past_hours" => opts.past_hours = Some(query::parse_value(&k, &v)?),
+                "start_date" => opts.start_date = Some(query::parse_date(&k, &v)?),
+                "end_date" => opts.end_date = Some(query::parse_date(&k, &v)?),
+                "start_hour" => opts.start_hour = Some(query::parse_datetime(&k, &v)?),
+                "end_hour" => opts.end_hour = Some(query::parse_datetime(&k, &v)?),
+                "cell_selection" => opts.cell_selection = Some(v.try_into()?),
+                "apikey" => opts.apikey = Some(v),
+                // Always requested as unixtime
+                "timeformat" => {}
+                _ => unknown.push((k, v)),
+            }
+        }
+
+        let (Some(lat), Some(lng)) = (lat, lng) else {
+            return Err(errors::ConversionError::MissingQueryParameter {
+                name: "latitude,longitude".into(),
+            });
+        };
+        opts.location = location::Location { lat, lng };
+
+        Ok((opts, unknown))
+    }
+
+    /// Build the request URL for the given endpoint, for example `client.air_quality_endpoint`.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the endpoint is not a valid URL.
+    pub fn to_url(&self, endpoint: &str) -> Result<reqwest::Url, Box<dyn Error>> {
+        Ok(reqwest::Url::parse_with_params(
+            endpoint,
+            self.clone().into_params(),
+        )?)
+    }
+
     fn into_params(self) -> Vec<(String, String)> {
         let mut params: Vec<(String, String)> = Vec::new();
 
@@ -36,14 +540,14 @@ impl Options {
         params.push(("longitude".into(), self.location.lng.to_string()));
         params.push(("timeformat".into(), "unixtime".into()));
         if !self.hourly.is_empty() {
-            params.push(("hourly".into(), self.hourly.join(",")));
+            params.push(("hourly".into(), join_pollutants(&self.hourly)));
         }
         if !self.current.is_empty() {
-            params.push(("current".into(), self.current.join(",")));
+            params.push(("current".into(), join_pollutants(&self.current)));
         }
 
         if let Some(domains) = self.domains {
-            params.push(("domains".into(), domains));
+            params.push(("domains".into(), domains.to_string()));
         }
         if let Some(time_zone) = self.time_zone {
             params.push(("timezone".into(), time_zone));
@@ -69,6 +573,18 @@ impl Options {
         if let Some(end_date) = self.end_date {
             params.push(("end_date".into(), end_date.format("%Y-%m-%d").to_string()));
         }
+        if let Some(start_hour) = self.start_hour {
+            params.push((
+                "start_hour".into(),
+                start_hour.format("%Y-%m-%dT%H:%M").to_string(),
+            ));
+        }
+        if let Some(end_hour) = self.end_hour {
+            params.push((
+                "end_hour".into(),
+                end_hour.format("%Y-%m-%dT%H:%M").to_string(),
+            ));
+        }
         if let Some(cell_selection) = self.cell_selection {
             params.push(("cell_selection".into(), cell_selection.to_string()));
         }
@@ -80,6 +596,81 @@ impl Options {
     }
 }
 
+fn parse_pollutants(value: &str) -> Result<Vec<Pollutant>, errors::ConversionError> {
+    query::parse_list(value)
+        .into_iter()
+        .map(Pollutant::try_from)
+        .collect()
+}
+
+fn join_pollutants(pollutants: &[Pollutant]) -> String {
+    pollutants
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Fluent builder for air quality [`Options`].
+///
+/// ```
+/// use open_meteo_rs::air_quality::{AirQualityRequest, Pollutant};
+///
+/// let opts = AirQualityRequest::at(52.52, 13.41)
+///     .hourly([Pollutant::Pm10, Pollutant::Pm2_5])
+///     .forecast_days(2)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct AirQualityRequest {
+    options: Options,
+}
+
+builder::request_builder!(AirQualityRequest {
+    lists {
+        hourly: Pollutant,
+        current: Pollutant,
+    }
+    values {
+        domains: AirQualityDomain,
+        past_days: u8,
+        forecast_days: u8,
+        past_hours: u32,
+        forecast_hours: u32,
+    }
+});
+
+impl AirQualityRequest {
+    /// Request hourly concentrations of every pollen species
+    #[must_use]
+    pub fn pollen(self) -> Self {
+        self.hourly(PollenSpecies::ALL.map(PollenSpecies::pollutant))
+    }
+
+    /// Request data between two hours (inclusive)
+    #[must_use]
+    pub fn between_hours(
+        mut self,
+        start_hour: chrono::NaiveDateTime,
+        end_hour: chrono::NaiveDateTime,
+    ) -> Self {
+        self.options.start_hour = Some(start_hour);
+        self.options.end_hour = Some(end_hour);
+        self
+    }
+
+    /// Validate and return the options.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the options are invalid or contradictory.
+    pub fn build(self) -> Result<Options, errors::ValidationError> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ApiAirQualityResponse {
     pub latitude: Option<f64>,
@@ -95,14 +686,26 @@ struct ApiAirQualityResponse {
     pub hourly: Option<HashMap<String, serde_json::Value>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct AirQualityResult {
+    pub metadata: Metadata,
     pub current: Option<CurrentResult>,
     pub hourly: Option<Vec<ForecastResultHourly>>,
 }
 
 fn api_to_result(api_res: ApiAirQualityResponse) -> Result<AirQualityResult, Box<dyn Error>> {
-    let mut result = AirQualityResult::default();
+    let mut result = AirQualityResult {
+        metadata: Metadata {
+            latitude: api_res.latitude,
+            longitude: api_res.longitude,
+            elevation: api_res.elevation,
+            generationtime_ms: api_res.generationtime_ms,
+            utc_offset_seconds: api_res.utc_offset_seconds,
+            timezone: api_res.timezone.clone(),
+            timezone_abbreviation: api_res.timezone_abbreviation.clone(),
+        },
+        ..Default::default()
+    };
 
     if let Some(current) = api_res.current {
         let api_units = api_res.current_units.clone();
@@ -138,26 +741,29 @@ fn api_to_result(api_res: ApiAirQualityResponse) -> Result<AirQualityResult, Box
 
             // Iterate on times
             for (idx, time) in hourly_date_times.iter().enumerate() {
-                let mut hourly_rec = ForecastResultHourly::default();
+                let mut hourly_rec = ForecastResultHourly {
+                    datetime: *time,
+                    ..Default::default()
+                };
                 // Iterates on values
                 for (k, v) in &hourly {
                     if k == "time" {
                         continue;
                     }
 
-                    let v_arr = v.as_array().expect("Cannot decode JSON");
-
-                    let value = v_arr[idx].clone();
+                    let Some(value) = v.as_array().and_then(|arr| arr.get(idx)) else {
+                        return Err("cannot decode properly json input".into());
+                    };
                     // Try to find unit
                     let unit = api_units.as_ref().and_then(|units| units.get(k).cloned());
                     // Push to hourly record
-                    hourly_rec = ForecastResultHourly {
-                        datetime: *time,
-                        values: HashMap::from_iter([(
-                            k.clone(),
-                            ForecastResultItem { unit, value },
-                        )]),
-                    }
+                    hourly_rec.values.insert(
+                        k.clone(),
+                        ForecastResultItem {
+                            unit,
+                            value: value.clone(),
+                        },
+                    );
                 }
 
                 // Push hourly rec
@@ -172,12 +778,31 @@ fn api_to_result(api_res: ApiAirQualityResponse) -> Result<AirQualityResult, Box
 }
 
 impl client::Client {
-    /// Request forecast data
+    /// Request air quality data. Date or hour ranges longer than [`CHUNK_DAYS`] are requested
+    /// in several calls and merged, metadata and current values come from the first one.
     ///
     /// ### Errors
     ///
-    /// Return an `Err` if api call fail or in case of network error.
+    /// Return an `Err` if options are invalid, if api call fail or in case of network error.
     pub async fn air_quality(&self, opts: Options) -> Result<AirQualityResult, Box<dyn Error>> {
+        opts.validate()?;
+
+        let mut result: Option<AirQualityResult> = None;
+        for chunk in opts.chunks(CHUNK_DAYS) {
+            let res = self.air_quality_request(chunk).await?;
+            match result.as_mut() {
+                Some(result) => {
+                    let hourly = res.hourly.unwrap_or_default();
+                    result.hourly.get_or_insert_with(Vec::new).extend(hourly);
+                }
+                None => result = Some(res),
+            }
+        }
+
+        Ok(result.unwrap_or_default())
+    }
+
+    async fn air_quality_request(&self, opts: Options) -> Result<AirQualityResult, Box<dyn Error>> {
         let url = reqwest::Url::parse_with_params(&self.air_quality_endpoint, opts.into_params())?;
         let res = self.http_client.get(url).send().await?;
 
@@ -199,6 +824,117 @@ mod tests {
     use crate::*;
     use chrono::Duration;
 
+    #[test]
+    fn query_string_round_trip() {
+        let opts = AirQualityRequest::at(52.52, 13.41)
+            .hourly([Pollutant::Pm10, Pollutant::Pm2_5])
+            .current([Pollutant::EuropeanAqi])
+            .domains(AirQualityDomain::CamsEurope)
+            .cell_selection(CellSelection::Sea)
+            .past_days(3)
+            .build()
+            .unwrap();
+
+        let url = opts
+            .to_url("https://air-quality-api.open-meteo.com/v1/air-quality")
+            .unwrap();
+        assert_eq!(Options::from_query_string(url.as_str()).unwrap(), opts);
+    }
+
+    #[test]
+    fn parse_enums() {
+        assert_eq!(Pollutant::try_from("pm2_5").unwrap(), Pollutant::Pm2_5);
+        assert_eq!(Pollutant::UsAqiPm2_5.to_string(), "us_aqi_pm2_5");
+        let pm1 = Pollutant::try_from("pm1").unwrap();
+        assert_eq!(pm1, Pollutant::Other("pm1".into()));
+        assert_eq!(pm1.to_string(), "pm1");
+        assert!(Pollutant::try_from("").is_err());
+        assert!(AirQualityDomain::try_from("cams").is_err());
+        assert_eq!(
+            Pollutant::GrassPollen.pollen_species(),
+            Some(PollenSpecies::Grass)
+        );
+        assert_eq!(
+            PollenSpecies::try_from("olive").unwrap(),
+            PollenSpecies::Olive
+        );
+    }
+
+    #[test]
+    fn decode_several_hourly_variables() {
+        // Every variable used to overwrite the previous one, keeping only the last
+        let api_res: ApiAirQualityResponse = serde_json::from_value(serde_json::json!({
+            "latitude": 52.52,
+            "longitude": 13.42,
+            "utc_offset_seconds": 3600,
+            "hourly_units": {"time": "unixtime", "pm10": "μg/m³", "pm2_5": "μg/m³", "ozone": "μg/m³"},
+            "hourly": {
+                "time": [1_704_067_200, 1_704_070_800],
+                "pm10": [20.5, 21.0],
+                "pm2_5": [12.0, null],
+                "ozone": [40.0, 41.5]
+            }
+        }))
+        .unwrap();
+
+        let hourly = api_to_result(api_res).unwrap().hourly.unwrap();
+        assert_eq!(hourly.len(), 2);
+        assert_eq!(
+            hourly[0].datetime,
+            chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+                .unwrap()
+                .and_hms_opt(1, 0, 0)
+                .unwrap()
+        );
+        for rec in &hourly {
+            assert_eq!(rec.values.len(), 3);
+            assert_eq!(rec.values["pm10"].unit.as_deref(), Some("μg/m³"));
+        }
+        assert_eq!(hourly[0].value_f64("pm2_5"), Some(12.0));
+        assert_eq!(hourly[1].value_f64("pm2_5"), None);
+        assert_eq!(hourly[1].value_f64("ozone"), Some(41.5));
+    }
+
+    #[test]
+    fn hour_ranges_and_chunks() {
+        let start = FIRST_DATE.and_hms_opt(6, 0, 0).unwrap();
+        let opts = AirQualityRequest::at(52.52, 13.41)
+            .hourly([Pollutant::Pm10])
+            .between_hours(start, start + Duration::days(200))
+            .build()
+            .unwrap();
+
+        let url = opts
+            .to_url("https://air-quality-api.open-meteo.com/v1/air-quality")
+            .unwrap();
+        assert!(url.as_str().contains("start_hour=2013-01-01T06%3A00"));
+        assert_eq!(Options::from_query_string(url.as_str()).unwrap(), opts);
+
+        let chunks = opts.chunks(CHUNK_DAYS);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(
+            chunks[1].start_hour,
+            Some(start + Duration::days(CHUNK_DAYS))
+        );
+        assert_eq!(chunks[2].end_hour, opts.end_hour);
+
+        let opts = AirQualityRequest::at(52.52, 13.41)
+            .between(FIRST_DATE, FIRST_DATE + Duration::days(91))
+            .build()
+            .unwrap();
+        assert_eq!(opts.chunks(CHUNK_DAYS).len(), 1);
+
+        for builder in [
+            AirQualityRequest::at(52.52, 13.41).between_hours(start, start - Duration::hours(1)),
+            AirQualityRequest::at(52.52, 13.41)
+                .between_hours(start, start)
+                .past_days(1),
+            AirQualityRequest::at(52.52, 13.41).between(FIRST_DATE - Duration::days(1), FIRST_DATE),
+        ] {
+            assert!(builder.build().is_err());
+        }
+    }
+
     #[tokio::test]
     async fn get_air_quality_single() {
         let clt = Client::new();
@@ -207,8 +943,8 @@ mod tests {
                 lat: 52.52,
                 lng: 13.41,
             },
-            current: vec!["sulphur_dioxide".into()],
-            hourly: vec!["ozone".into(), "dust".into()],
+            current: vec![Pollutant::SulphurDioxide],
+            hourly: vec![Pollutant::Ozone, Pollutant::Dust],
             time_zone: Some(chrono_tz::Tz::Europe__Paris.to_string()),
             start_date: Some(chrono::Utc::now().date_naive()),
             end_date: Some((chrono::Utc::now() + Duration::days(4)).date_naive()),
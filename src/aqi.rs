@@ -0,0 +1,549 @@
+//! European and US EPA air quality indices computed from hourly pollutant concentrations.
+//!
+//! Concentrations are expected in μg/m³, as returned by the CAMS models; mg/m³ values are
+//! converted and values in any other unit, or without unit, are skipped. They are averaged
+//! over the trailing window of each pollutant (24 hours for particulate matter, 8 hours for
+//! ozone and carbon monoxide with the US scale) before the breakpoints are applied. A window
+//! needs 75% of its hours to be valid, so the first hours of a response have no PM index;
+//! request a few `past_days` to cover them.
+//!
+//! ```no_run
+//! # async fn run(client: open_meteo_rs::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! use open_meteo_rs::air_quality::{AirQualityRequest, Pollutant};
+//! use open_meteo_rs::aqi::{AqiCalculator, Scale};
+//!
+//! let opts = AirQualityRequest::at(52.52, 13.41)
+//!     .hourly(Scale::UnitedStates.pollutants().iter().cloned())
+//!     .past_days(1)
+//!     .build()?;
+//! let res = client.air_quality(opts).await?;
+//!
+//! let records = AqiCalculator::new(Scale::UnitedStates)
+//!     .with_window(Pollutant::Ozone, 1)
+//!     .compute(res.hourly.as_deref().unwrap_or_default());
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::air_quality::{AirQualityResult, Pollutant};
+use crate::forecast::{ForecastResultHourly, ForecastResultItem};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+/// Molar volume in litres at 25 °C and 1 atm, used to convert μg/m³ to ppb
+const MOLAR_VOLUME: f64 = 24.45;
+
+/// Concentration low, concentration high, index low, index high
+type Breakpoint = (f64, f64, f64, f64);
+
+const EU_PM2_5: &[Breakpoint] = &[
+    (0.0, 10.0, 0.0, 20.0),
+    (10.0, 20.0, 20.0, 40.0),
+    (20.0, 25.0, 40.0, 60.0),
+    (25.0, 50.0, 60.0, 80.0),
+    (50.0, 75.0, 80.0, 100.0),
+    (75.0, 800.0, 100.0, 500.0),
+];
+const EU_PM10: &[Breakpoint] = &[
+    (0.0, 20.0, 0.0, 20.0),
+    (20.0, 40.0, 20.0, 40.0),
+    (40.0, 50.0, 40.0, 60.0),
+    (50.0, 100.0, 60.0, 80.0),
+    (100.0, 150.0, 80.0, 100.0),
+    (150.0, 1200.0, 100.0, 500.0),
+];
+const EU_NO2: &[Breakpoint] = &[
+    (0.0, 40.0, 0.0, 20.0),
+    (40.0, 90.0, 20.0, 40.0),
+    (90.0, 120.0, 40.0, 60.0),
+    (120.0, 230.0, 60.0, 80.0),
+    (230.0, 340.0, 80.0, 100.0),
+    (340.0, 1000.0, 100.0, 500.0),
+];
+const EU_O3: &[Breakpoint] = &[
+    (0.0, 50.0, 0.0, 20.0),
+    (50.0, 100.0, 20.0, 40.0),
+    (100.0, 130.0, 40.0, 60.0),
+    (130.0, 240.0, 60.0, 80.0),
+    (240.0, 380.0, 80.0, 100.0),
+    (380.0, 800.0, 100.0, 500.0),
+];
+const EU_SO2: &[Breakpoint] = &[
+    (0.0, 100.0, 0.0, 20.0),
+    (100.0, 200.0, 20.0, 40.0),
+    (200.0, 350.0, 40.0, 60.0),
+    (350.0, 500.0, 60.0, 80.0),
+    (500.0, 750.0, 80.0, 100.0),
+    (750.0, 1250.0, 100.0, 500.0),
+];
+
+/// μg/m³, 2024 revision
+const US_PM2_5: &[Breakpoint] = &[
+    (0.0, 9.0, 0.0, 50.0),
+    (9.1, 35.4, 51.0, 100.0),
+    (35.5, 55.4, 101.0, 150.0),
+    (55.5, 125.4, 151.0, 200.0),
+    (125.5, 225.4, 201.0, 300.0),
+    (225.5, 325.4, 301.0, 500.0),
+];
+/// μg/m³
+const US_PM10: &[Breakpoint] = &[
+    (0.0, 54.0, 0.0, 50.0),
+    (55.0, 154.0, 51.0, 100.0),
+    (155.0, 254.0, 101.0, 150.0),
+    (255.0, 354.0, 151.0, 200.0),
+    (355.0, 424.0, 201.0, 300.0),
+    (425.0, 604.0, 301.0, 500.0),
+];
+/// ppb, 8-hour average
+const US_O3: &[Breakpoint] = &[
+    (0.0, 54.0, 0.0, 50.0),
+    (55.0, 70.0, 51.0, 100.0),
+    (71.0, 85.0, 101.0, 150.0),
+    (86.0, 105.0, 151.0, 200.0),
+    (106.0, 200.0, 201.0, 300.0),
+];
+/// ppm, 8-hour average
+const US_CO: &[Breakpoint] = &[
+    (0.0, 4.4, 0.0, 50.0),
+    (4.5, 9.4, 51.0, 100.0),
+    (9.5, 12.4, 101.0, 150.0),
+    (12.5, 15.4, 151.0, 200.0),
+    (15.5, 30.4, 201.0, 300.0),
+    (30.5, 50.4, 301.0, 500.0),
+];
+/// ppb, 1-hour average
+const US_NO2: &[Breakpoint] = &[
+    (0.0, 53.0, 0.0, 50.0),
+    (54.0, 100.0, 51.0, 100.0),
+    (101.0, 360.0, 101.0, 150.0),
+    (361.0, 649.0, 151.0, 200.0),
+    (650.0, 1249.0, 201.0, 300.0),
+    (1250.0, 2049.0, 301.0, 500.0),
+];
+/// ppb, 1-hour average
+const US_SO2: &[Breakpoint] = &[
+    (0.0, 35.0, 0.0, 50.0),
+    (36.0, 75.0, 51.0, 100.0),
+    (76.0, 185.0, 101.0, 150.0),
+    (186.0, 304.0, 151.0, 200.0),
+    (305.0, 604.0, 201.0, 300.0),
+    (605.0, 1004.0, 301.0, 500.0),
+];
+
+/// Index scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Scale {
+    /// European Environment Agency index, 0 to 100 and above
+    European,
+    /// US EPA index, 0 to 500
+    UnitedStates,
+}
+
+impl Scale {
+    /// Pollutants contributing to the index
+    #[must_use]
+    pub fn pollutants(self) -> &'static [Pollutant] {
+        match self {
+            Self::European => &[
+                Pollutant::Pm2_5,
+                Pollutant::Pm10,
+                Pollutant::NitrogenDioxide,
+                Pollutant::Ozone,
+                Pollutant::SulphurDioxide,
+            ],
+            Self::UnitedStates => &[
+                Pollutant::Pm2_5,
+                Pollutant::Pm10,
+                Pollutant::NitrogenDioxide,
+                Pollutant::Ozone,
+                Pollutant::SulphurDioxide,
+                Pollutant::CarbonMonoxide,
+            ],
+        }
+    }
+
+    /// Default averaging window in hours, `None` if the pollutant is not part of the index
+    #[must_use]
+    pub fn window(self, pollutant: &Pollutant) -> Option<usize> {
+        match (self, pollutant) {
+            (_, Pollutant::Pm2_5 | Pollutant::Pm10) => Some(24),
+            (Self::UnitedStates, Pollutant::Ozone | Pollutant::CarbonMonoxide) => Some(8),
+            _ if self.pollutants().contains(pollutant) => Some(1),
+            _ => None,
+        }
+    }
+
+    /// Sub-index of an averaged concentration in μg/m³
+    #[must_use]
+    pub fn sub_index(self, pollutant: &Pollutant, concentration: f64) -> Option<f64> {
+        if !concentration.is_finite() || concentration < 0.0 {
+            return None;
+        }
+
+        match self {
+            Self::European => {
+                let table = match pollutant {
+                    Pollutant::Pm2_5 => EU_PM2_5,
+                    Pollutant::Pm10 => EU_PM10,
+                    Pollutant::NitrogenDioxide => EU_NO2,
+                    Pollutant::Ozone => EU_O3,
+                    Pollutant::SulphurDioxide => EU_SO2,
+                    _ => return None,
+                };
+                Some(interpolate(table, concentration))
+            }
+            Self::UnitedStates => {
+                // Concentrations are truncated to the precision of the breakpoints
+                let (table, value) = match pollutant {
+                    Pollutant::Pm2_5 => (US_PM2_5, truncate(concentration, 1)),
+                    Pollutant::Pm10 => (US_PM10, truncate(concentration, 0)),
+                    Pollutant::Ozone => (US_O3, truncate(to_ppb(concentration, 48.00), 0)),
+                    Pollutant::NitrogenDioxide => {
+                        (US_NO2, truncate(to_ppb(concentration, 46.01), 0))
+                    }
+                    Pollutant::SulphurDioxide => {
+                        (US_SO2, truncate(to_ppb(concentration, 64.07), 0))
+                    }
+                    Pollutant::CarbonMonoxide => {
+                        (US_CO, truncate(to_ppb(concentration, 28.01) / 1000.0, 1))
+                    }
+                    _ => return None,
+                };
+                Some(interpolate(table, value).round())
+            }
+        }
+    }
+
+    /// Category of an index value
+    #[must_use]
+    pub fn category(self, index: f64) -> Category {
+        match self {
+            Self::European => match index {
+                i if i <= 20.0 => Category::Good,
+                i if i <= 40.0 => Category::Fair,
+                i if i <= 60.0 => Category::Moderate,
+                i if i <= 80.0 => Category::Poor,
+                i if i <= 100.0 => Category::VeryPoor,
+                _ => Category::ExtremelyPoor,
+            },
+            Self::UnitedStates => match index {
+                i if i <= 50.0 => Category::Good,
+                i if i <= 100.0 => Category::Moderate,
+                i if i <= 150.0 => Category::UnhealthyForSensitiveGroups,
+                i if i <= 200.0 => Category::Unhealthy,
+                i if i <= 300.0 => Category::VeryUnhealthy,
+                _ => Category::Hazardous,
+            },
+        }
+    }
+}
+
+fn to_ppb(concentration: f64, molecular_weight: f64) -> f64 {
+    concentration * MOLAR_VOLUME / molecular_weight
+}
+
+fn truncate(value: f64, decimals: i32) -> f64 {
+    let factor = 10f64.powi(decimals);
+    (value * factor).floor() / factor
+}
+
+/// Linear interpolation within the matching band, values above the last band are capped
+fn interpolate(table: &[Breakpoint], concentration: f64) -> f64 {
+    let Some(&(c_low, c_high, i_low, i_high)) = table
+        .iter()
+        .find(|(_, c_high, _, _)| concentration <= *c_high)
+    else {
+        return table.last().map_or(0.0, |bp| bp.3);
+    };
+
+    i_low + (i_high - i_low) / (c_high - c_low) * (concentration.max(c_low) - c_low)
+}
+
+/// Category of the European and US scales
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Category {
+    Good,
+    /// European scale only
+    Fair,
+    Moderate,
+    /// European scale only
+    Poor,
+    /// European scale only
+    VeryPoor,
+    /// European scale only
+    ExtremelyPoor,
+    /// US scale only
+    UnhealthyForSensitiveGroups,
+    /// US scale only
+    Unhealthy,
+    /// US scale only
+    VeryUnhealthy,
+    /// US scale only
+    Hazardous,
+}
+
+impl Category {
+    /// Official label
+    #[must_use]
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Good => "Good",
+            Self::Fair => "Fair",
+            Self::Moderate => "Moderate",
+            Self::Poor => "Poor",
+            Self::VeryPoor => "Very poor",
+            Self::ExtremelyPoor => "Extremely poor",
+            Self::UnhealthyForSensitiveGroups => "Unhealthy for Sensitive Groups",
+            Self::Unhealthy => "Unhealthy",
+            Self::VeryUnhealthy => "Very Unhealthy",
+            Self::Hazardous => "Hazardous",
+        }
+    }
+
+    /// Official color of the category in `scale`, as a hex RGB string. `Good` and `Moderate`
+    /// exist in both scales with different colors.
+    #[must_use]
+    pub fn color(self, scale: Scale) -> &'static str {
+        match (scale, self) {
+            (Scale::European, Self::Good) => "#50F0E6",
+            (_, Self::Fair) => "#50CCAA",
+            (Scale::European, Self::Moderate) => "#F0E641",
+            (_, Self::Poor) => "#FF5050",
+            (_, Self::VeryPoor) => "#960032",
+            (_, Self::ExtremelyPoor) => "#7D2181",
+            (Scale::UnitedStates, Self::Good) => "#00E400",
+            (Scale::UnitedStates, Self::Moderate) => "#FFFF00",
+            (_, Self::UnhealthyForSensitiveGroups) => "#FF7E00",
+            (_, Self::Unhealthy) => "#FF0000",
+            (_, Self::VeryUnhealthy) => "#8F3F97",
+            (_, Self::Hazardous) => "#7E0023",
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+/// Index of a single pollutant
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubIndex {
+    pub pollutant: Pollutant,
+    /// Concentration averaged over the window, in μg/m³
+    pub concentration: f64,
+    pub index: f64,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AqiRecord {
+    pub datetime: chrono::NaiveDateTime,
+    /// Maximum of the sub-indices, `None` if no pollutant had enough values
+    pub index: Option<f64>,
+    pub category: Option<Category>,
+    /// Pollutant with the highest sub-index
+    pub dominant: Option<Pollutant>,
+    pub sub_indices: Vec<SubIndex>,
+}
+
+/// Computes an index from consecutive hourly records
+#[derive(Debug, Clone)]
+pub struct AqiCalculator {
+    scale: Scale,
+    windows: HashMap<Pollutant, usize>,
+}
+
+impl AqiCalculator {
+    #[must_use]
+    pub fn new(scale: Scale) -> Self {
+        Self {
+            scale,
+            windows: HashMap::new(),
+        }
+    }
+
+    /// Override the averaging window of a pollutant, in hours
+    #[must_use]
+    pub fn with_window(mut self, pollutant: Pollutant, hours: usize) -> Self {
+        self.windows.insert(pollutant, hours.max(1));
+        self
+    }
+
+    /// One record per hourly record, in the same order
+    #[must_use]
+    pub fn compute(&self, hourly: &[ForecastResultHourly]) -> Vec<AqiRecord> {
+        let averages: Vec<(Pollutant, Vec<Option<f64>>)> = self
+            .scale
+            .pollutants()
+            .iter()
+            .filter_map(|pollutant| {
+                let window = self
+                    .windows
+                    .get(pollutant)
+                    .copied()
+                    .or_else(|| self.scale.window(pollutant))?;
+                let name = pollutant.to_string();
+                let values: Vec<Option<f64>> = hourly
+                    .iter()
+                    .map(|rec| micrograms(rec.values.get(&name)?))
+                    .collect();
+                Some((pollutant.clone(), rolling_mean(&values, window)))
+            })
+            .collect();
+
+        hourly
+            .iter()
+            .enumerate()
+            .map(|(idx, rec)| {
+                let sub_indices: Vec<SubIndex> = averages
+                    .iter()
+                    .filter_map(|(pollutant, values)| {
+                        let concentration = values[idx]?;
+                        Some(SubIndex {
+                            pollutant: pollutant.clone(),
+                            concentration,
+                            index: self.scale.sub_index(pollutant, concentration)?,
+                        })
+                    })
+                    .collect();
+
+                let dominant = sub_indices
+                    .iter()
+                    .max_by(|a, b| a.index.total_cmp(&b.index));
+
+                AqiRecord {
+                    datetime: rec.datetime,
+                    index: dominant.map(|s| s.index),
+                    category: dominant.map(|s| self.scale.category(s.index)),
+                    dominant: dominant.map(|s| s.pollutant.clone()),
+                    sub_indices,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Concentration in μg/m³, `None` if the value is missing or its unit is not a mass
+/// concentration
+fn micrograms(item: &ForecastResultItem) -> Option<f64> {
+    let factor = match item.unit.as_deref()? {
+        // Greek small letter mu and micro sign
+        "μg/m³" | "µg/m³" => 1.0,
+        "mg/m³" => 1000.0,
+        _ => return None,
+    };
+    Some(item.as_f64()? * factor)
+}
+
+/// Trailing mean, `None` unless 75% of the window has values
+#[allow(clippy::cast_precision_loss)]
+fn rolling_mean(values: &[Option<f64>], window: usize) -> Vec<Option<f64>> {
+    (0..values.len())
+        .map(|idx| {
+            let start = (idx + 1).saturating_sub(window);
+            let present: Vec<f64> = values[start..=idx].iter().flatten().copied().collect();
+            if present.len() * 4 < window * 3 {
+                return None;
+            }
+            Some(present.iter().sum::<f64>() / present.len() as f64)
+        })
+        .collect()
+}
+
+impl AirQualityResult {
+    /// Index of every hourly record with the default averaging windows
+    #[must_use]
+    pub fn aqi(&self, scale: Scale) -> Vec<AqiRecord> {
+        AqiCalculator::new(scale).compute(self.hourly.as_deref().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    fn records(values: &[(Pollutant, f64)], hours: i64) -> Vec<ForecastResultHourly> {
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_time(chrono::NaiveTime::MIN);
+        (0..hours)
+            .map(|h| ForecastResultHourly {
+                datetime: start + chrono::Duration::hours(h),
+                values: values
+                    .iter()
+                    .map(|(p, v)| {
+                        (
+                            p.to_string(),
+                            ForecastResultItem {
+                                unit: Some("μg/m³".into()),
+                                value: (*v).into(),
+                            },
+                        )
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn sub_indices() {
+        let us = Scale::UnitedStates;
+        assert_eq!(us.sub_index(&Pollutant::Pm2_5, 9.0), Some(50.0));
+        assert_eq!(us.sub_index(&Pollutant::Pm2_5, 35.4), Some(100.0));
+        assert_eq!(us.sub_index(&Pollutant::Pm2_5, 1000.0), Some(500.0));
+        assert_eq!(us.sub_index(&Pollutant::Pm10, 154.9), Some(100.0));
+        // 100 μg/m³ of ozone is 50.9 ppb
+        assert_eq!(us.sub_index(&Pollutant::Ozone, 100.0), Some(46.0));
+        assert_eq!(us.sub_index(&Pollutant::Dust, 10.0), None);
+        assert_eq!(us.category(120.0), Category::UnhealthyForSensitiveGroups);
+
+        let eu = Scale::European;
+        assert_eq!(eu.sub_index(&Pollutant::Pm10, 30.0), Some(30.0));
+        assert_eq!(eu.sub_index(&Pollutant::Ozone, 115.0), Some(50.0));
+        assert_eq!(eu.category(50.0), Category::Moderate);
+        assert_eq!(Category::Moderate.color(eu), "#F0E641");
+        assert_eq!(Category::Moderate.color(us), "#FFFF00");
+    }
+
+    #[test]
+    fn rolling_windows_and_dominant_pollutant() {
+        let hourly = records(&[(Pollutant::Pm10, 30.0), (Pollutant::Ozone, 115.0)], 24);
+
+        let records = AqiCalculator::new(Scale::European).compute(&hourly);
+        assert_eq!(records.len(), 24);
+        // PM needs 18 of 24 hours
+        assert_eq!(records[16].dominant, Some(Pollutant::Ozone));
+        assert_eq!(records[16].sub_indices.len(), 1);
+        assert_eq!(records[17].sub_indices.len(), 2);
+        assert_eq!(records[17].index, Some(50.0));
+        assert_eq!(records[17].category, Some(Category::Moderate));
+
+        let records = AqiCalculator::new(Scale::European)
+            .with_window(Pollutant::Pm10, 1)
+            .compute(&hourly);
+        assert_eq!(records[0].sub_indices.len(), 2);
+    }
+
+    #[test]
+    fn concentrations_in_other_units() {
+        let mut hourly = records(&[(Pollutant::Ozone, 115.0)], 2);
+        // Same concentration in mg/m³, then in ppb which cannot be converted
+        for (rec, (unit, value)) in hourly.iter_mut().zip([("mg/m³", 0.115), ("ppb", 57.5)]) {
+            rec.values.insert(
+                Pollutant::Ozone.to_string(),
+                ForecastResultItem {
+                    unit: Some(unit.into()),
+                    value: value.into(),
+                },
+            );
+        }
+
+        let records = AqiCalculator::new(Scale::European).compute(&hourly);
+        assert_eq!(records[0].index, Some(50.0));
+        assert!(records[1].sub_indices.is_empty());
+        assert_eq!(records[1].index, None);
+    }
+}
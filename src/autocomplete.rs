@@ -0,0 +1,265 @@
+//! Search-as-you-type on top of the geocoding API.
+
+use super::{client, geocoding};
+use std::collections::{HashMap, VecDeque};
+use std::error::Error;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// The geocoding API needs at least 2 characters for fuzzy matching
+pub const DEFAULT_MIN_LENGTH: usize = 2;
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(150);
+/// Number of queries kept in the cache, the oldest ones are evicted first
+pub const DEFAULT_CACHE_SIZE: usize = 256;
+/// Number of results requested when `Options::count` is not set
+const DEFAULT_COUNT: usize = 10;
+
+/// Geocoding autocompletion with a bounded prefix cache. Each call to `complete` supersedes the
+/// previous ones: they return `None` once a newer query is typed, and their in-flight
+/// request is dropped.
+///
+/// ```no_run
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// use open_meteo_rs::autocomplete::Autocompleter;
+/// use open_meteo_rs::geocoding::Options;
+///
+/// let completer = Autocompleter::new(open_meteo_rs::Client::new(), Options::default());
+/// if let Some(results) = completer.complete("Pari").await? {
+///     println!("{results:?}");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Autocompleter {
+    client: client::Client,
+    opts: geocoding::Options,
+    min_length: usize,
+    debounce: Duration,
+    cache: Mutex<Cache>,
+    generation: tokio::sync::watch::Sender<u64>,
+}
+
+impl Autocompleter {
+    /// `opts` is used for every request, its `name` is replaced by the query
+    #[must_use]
+    pub fn new(client: client::Client, opts: geocoding::Options) -> Self {
+        Self {
+            client,
+            opts,
+            min_length: DEFAULT_MIN_LENGTH,
+            debounce: DEFAULT_DEBOUNCE,
+            cache: Mutex::new(Cache::new(DEFAULT_CACHE_SIZE)),
+            generation: tokio::sync::watch::channel(0).0,
+        }
+    }
+
+    #[must_use]
+    pub fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    #[must_use]
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// Maximum number of queries kept in the cache
+    #[must_use]
+    pub fn with_cache_size(mut self, size: usize) -> Self {
+        self.cache = Mutex::new(Cache::new(size));
+        self
+    }
+
+    /// Results for `query`, `None` if a newer query superseded it. Queries shorter than the
+    /// minimum length return no results without calling the API.
+    ///
+    /// ### Errors
+    ///
+    /// Will return `Err` if api return an invaid response or in case of network error.
+    pub async fn complete(
+        &self,
+        query: &str,
+    ) -> Result<Option<Vec<geocoding::GeocodingResult>>, Box<dyn Error>> {
+        let key = query.trim().to_lowercase();
+        self.generation.send_modify(|generation| *generation += 1);
+        let generation = *self.generation.borrow();
+        // Subscribed before any await so that newer queries always wake `changed`
+        let mut superseded = self.generation.subscribe();
+
+        if key.chars().count() < self.min_length {
+            return Ok(Some(Vec::new()));
+        }
+        if let Some(results) = self.cached(&key) {
+            return Ok(Some(results));
+        }
+
+        tokio::time::sleep(self.debounce).await;
+        if *self.generation.borrow() != generation {
+            return Ok(None);
+        }
+
+        let opts = geocoding::Options {
+            name: Some(query.trim().to_string()),
+            ..self.opts.clone()
+        };
+        let res = tokio::select! {
+            res = self.client.geocoding(opts) => res?,
+            _ = superseded.changed() => return Ok(None),
+        };
+
+        let results = res.results.unwrap_or_default();
+        self.lock_cache().insert(key, results.clone());
+
+        if *self.generation.borrow() != generation {
+            return Ok(None);
+        }
+        Ok(Some(results))
+    }
+
+    /// Drop every cached result
+    pub fn clear_cache(&self) {
+        self.lock_cache().clear();
+    }
+
+    /// Cached results of `key`, or results of a shorter cached prefix filtered locally when
+    /// that prefix returned fewer results than requested, meaning there are no other matches
+    fn cached(&self, key: &str) -> Option<Vec<geocoding::GeocodingResult>> {
+        let mut cache = self.lock_cache();
+        if let Some(results) = cache.get(key) {
+            return Some(results.clone());
+        }
+
+        let count = self.opts.count.map_or(DEFAULT_COUNT, usize::from);
+        let results: Vec<geocoding::GeocodingResult> = key
+            .char_indices()
+            .rev()
+            .map(|(idx, _)| &key[..idx])
+            .filter(|prefix| prefix.chars().count() >= self.min_length)
+            .find_map(|prefix| cache.get(prefix).filter(|results| results.len() < count))?
+            .iter()
+            .filter(|result| {
+                result
+                    .name
+                    .as_ref()
+                    .is_some_and(|name| name.to_lowercase().starts_with(key))
+            })
+            .cloned()
+            .collect();
+
+        cache.insert(key.to_string(), results.clone());
+        Some(results)
+    }
+
+    fn lock_cache(&self) -> std::sync::MutexGuard<'_, Cache> {
+        self.cache
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// Results by query, bounded to `size` queries
+#[derive(Debug)]
+struct Cache {
+    size: usize,
+    results: HashMap<String, Vec<geocoding::GeocodingResult>>,
+    /// Queries from the oldest to the newest
+    order: VecDeque<String>,
+}
+
+impl Cache {
+    fn new(size: usize) -> Self {
+        Self {
+            size,
+            results: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, key: &str) -> Option<&Vec<geocoding::GeocodingResult>> {
+        self.results.get(key)
+    }
+
+    fn insert(&mut self, key: String, results: Vec<geocoding::GeocodingResult>) {
+        if self.results.insert(key.clone(), results).is_none() {
+            self.order.push_back(key);
+        }
+        while self.order.len() > self.size {
+            if let Some(oldest) = self.order.pop_front() {
+                self.results.remove(&oldest);
+            }
+        }
+    }
+
+    fn clear(&mut self) {
+        self.results.clear();
+        self.order.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn place(name: &str) -> geocoding::GeocodingResult {
+        geocoding::GeocodingResult {
+            name: Some(name.into()),
+            ..Default::default()
+        }
+    }
+
+    fn completer() -> Autocompleter {
+        // Any request would fail
+        let client = client::Client::new().with_geocoding_endpoint("http://127.0.0.1:9".into());
+        Autocompleter::new(client, geocoding::Options::default())
+            .with_debounce(Duration::from_millis(20))
+    }
+
+    #[tokio::test]
+    async fn complete_from_cache() {
+        let completer = completer();
+        assert_eq!(completer.complete("p").await.unwrap().unwrap().len(), 0);
+
+        completer.lock_cache().insert(
+            "par".into(),
+            vec![place("Paris"), place("Parma"), place("Pardubice")],
+        );
+        let results = completer.complete("PARi").await.unwrap().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].name.as_deref(), Some("Paris"));
+        assert!(completer.lock_cache().get("pari").is_some());
+
+        completer.clear_cache();
+        assert!(completer.complete("pari").await.is_err());
+    }
+
+    #[test]
+    fn bounded_cache() {
+        let mut cache = Cache::new(2);
+        cache.insert("ber".into(), vec![place("Berlin"), place("Bern")]);
+        cache.insert("berl".into(), vec![place("Berlin")]);
+        cache.insert("berl".into(), vec![place("Berlin")]);
+        cache.insert("bern".into(), vec![place("Bern")]);
+
+        assert!(cache.get("ber").is_none());
+        assert!(cache.get("berl").is_some());
+        assert!(cache.get("bern").is_some());
+    }
+
+    #[tokio::test]
+    async fn supersede_queries() {
+        let completer = completer();
+        completer
+            .lock_cache()
+            .insert("berl".into(), vec![place("Berlin")]);
+
+        let (first, second) = tokio::join!(completer.complete("ber"), async {
+            tokio::task::yield_now().await;
+            completer.complete("berl").await
+        });
+        assert!(first.unwrap().is_none());
+        assert_eq!(second.unwrap().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,86 @@
+//! Setters shared by the fluent request builders.
+
+/// Implement the setters of a builder wrapping an `options` field.
+///
+/// Every builder gets `at`, `location`, `time_zone`, `between`, `cell_selection` and `apikey`.
+/// `lists` are variables appended to a `Vec`, `values` are optional fields set as is.
+macro_rules! request_builder {
+    (
+        $builder:ident {
+            lists { $($list:ident: $item:ty),* $(,)? }
+            values { $($(#[$meta:meta])* $field:ident: $ty:ty),* $(,)? }
+        }
+    ) => {
+        impl $builder {
+            #[must_use]
+            pub fn at(lat: f64, lng: f64) -> Self {
+                Self {
+                    options: Options {
+                        location: location::Location { lat, lng },
+                        ..Default::default()
+                    },
+                }
+            }
+
+            #[must_use]
+            pub fn location(mut self, location: location::Location) -> Self {
+                self.options.location = location;
+                self
+            }
+
+            $(
+                #[must_use]
+                pub fn $list<S: Into<$item>>(
+                    mut self,
+                    variables: impl IntoIterator<Item = S>,
+                ) -> Self {
+                    self.options
+                        .$list
+                        .extend(variables.into_iter().map(Into::into));
+                    self
+                }
+            )*
+
+            $(
+                $(#[$meta])*
+                #[must_use]
+                pub fn $field(mut self, value: $ty) -> Self {
+                    self.options.$field = Some(value);
+                    self
+                }
+            )*
+
+            #[must_use]
+            pub fn time_zone(mut self, time_zone: impl Into<String>) -> Self {
+                self.options.time_zone = Some(time_zone.into());
+                self
+            }
+
+            /// Request data between two dates (inclusive)
+            #[must_use]
+            pub fn between(
+                mut self,
+                start_date: chrono::NaiveDate,
+                end_date: chrono::NaiveDate,
+            ) -> Self {
+                self.options.start_date = Some(start_date);
+                self.options.end_date = Some(end_date);
+                self
+            }
+
+            #[must_use]
+            pub fn cell_selection(mut self, cell_selection: CellSelection) -> Self {
+                self.options.cell_selection = Some(cell_selection);
+                self
+            }
+
+            #[must_use]
+            pub fn apikey(mut self, apikey: impl Into<String>) -> Self {
+                self.options.apikey = Some(apikey.into());
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use request_builder;
@@ -3,10 +3,13 @@ use std::time::Duration;
 const DEFAULT_FORECAST_ENDPOINT: &str = "https://api.open-meteo.com/v1/";
 const DEFAULT_ARCHIVE_ENDPOINT: &str = "https://archive-api.open-meteo.com/v1/";
 const DEFAULT_GEOCODING_ENDPOINT: &str = "https://geocoding-api.open-meteo.com/v1/search";
+const DEFAULT_GEOCODING_GET_ENDPOINT: &str = "https://geocoding-api.open-meteo.com/v1/get";
 const DEFAULT_AIR_QUALITY_ENDPOINT: &str = "https://air-quality-api.open-meteo.com/v1/air-quality";
 
 const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"),);
+#[allow(clippy::duration_suboptimal_units)]
 const DEFAULT_TIMEOUT: Duration = Duration::from_millis(5000);
+#[allow(clippy::duration_suboptimal_units)]
 const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_millis(2000);
 
 #[derive(Debug)]
@@ -16,6 +19,8 @@ pub struct Client {
     /// Archive API URL
     pub archive_endpoint: String,
     pub geocoding_endpoint: String,
+    /// Geocoding API URL used to get a location by id
+    pub geocoding_get_endpoint: String,
     pub air_quality_endpoint: String,
     pub http_client: reqwest::Client,
 }
@@ -26,6 +31,7 @@ impl Default for Client {
             forecast_endpoint: DEFAULT_FORECAST_ENDPOINT.to_string(),
             archive_endpoint: DEFAULT_ARCHIVE_ENDPOINT.to_string(),
             geocoding_endpoint: DEFAULT_GEOCODING_ENDPOINT.to_string(),
+            geocoding_get_endpoint: DEFAULT_GEOCODING_GET_ENDPOINT.to_string(),
             air_quality_endpoint: DEFAULT_AIR_QUALITY_ENDPOINT.to_string(),
             http_client: reqwest::Client::builder()
                 .timeout(DEFAULT_TIMEOUT)
@@ -54,6 +60,7 @@ impl Client {
             forecast_endpoint: forecast_endpoint.to_string(),
             archive_endpoint: archive_endpoint.to_string(),
             geocoding_endpoint: geocoding_endpoint.to_string(),
+            geocoding_get_endpoint: geocoding_get_endpoint(geocoding_endpoint),
             air_quality_endpoint: air_quality_endpoint.to_string(),
             ..Default::default()
         }
@@ -65,12 +72,20 @@ impl Client {
         self
     }
 
+    /// Set the geocoding search URL, the URL used to get a location by id is derived from it
     #[must_use]
     pub fn with_geocoding_endpoint(mut self, endpoint: String) -> Client {
+        self.geocoding_get_endpoint = geocoding_get_endpoint(&endpoint);
         self.geocoding_endpoint = endpoint;
         self
     }
 
+    #[must_use]
+    pub fn with_geocoding_get_endpoint(mut self, endpoint: String) -> Client {
+        self.geocoding_get_endpoint = endpoint;
+        self
+    }
+
     #[must_use]
     pub fn with_reqwest_client(mut self, client: reqwest::Client) -> Client {
         self.http_client = client;
@@ -78,6 +93,14 @@ impl Client {
     }
 }
 
+/// Sibling `get` URL of a geocoding `search` URL
+fn geocoding_get_endpoint(search_endpoint: &str) -> String {
+    match search_endpoint.strip_suffix("search") {
+        Some(base) => format!("{base}get"),
+        None => format!("{}/get", search_endpoint.trim_end_matches('/')),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,4 +117,22 @@ mod tests {
         let clt = Client::new().with_forecast_endpoint(endpoint.clone());
         assert_eq!(clt.forecast_endpoint, endpoint);
     }
+
+    #[test]
+    fn derive_geocoding_get_endpoint() {
+        let clt = Client::with_endpoints(
+            DEFAULT_FORECAST_ENDPOINT,
+            DEFAULT_ARCHIVE_ENDPOINT,
+            "http://localhost:8080/v1/search",
+            DEFAULT_AIR_QUALITY_ENDPOINT,
+        );
+        assert_eq!(clt.geocoding_get_endpoint, "http://localhost:8080/v1/get");
+
+        let clt = Client::new().with_geocoding_endpoint("http://proxy/geocoding/".into());
+        assert_eq!(clt.geocoding_get_endpoint, "http://proxy/geocoding/get");
+        assert_eq!(
+            geocoding_get_endpoint(DEFAULT_GEOCODING_ENDPOINT),
+            DEFAULT_GEOCODING_GET_ENDPOINT
+        );
+    }
 }
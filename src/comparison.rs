@@ -0,0 +1,205 @@
+//! Multi-model results, when `Options::models` lists several models.
+//!
+//! The API suffixes every variable with the model name, e.g. `temperature_2m_icon_seamless`.
+
+use crate::forecast::{
+    ForecastResult, ForecastResultDaily, ForecastResultHourly, ForecastResultItem, Model,
+};
+use crate::series::Series;
+use std::collections::{BTreeMap, HashMap};
+
+/// Split a result key into the variable name and the model suffix, the longest known model
+/// suffix wins. `None` if the key has no model suffix.
+#[must_use]
+pub fn split_model_suffix(key: &str) -> Option<(&str, Model)> {
+    key.match_indices('_').find_map(|(idx, _)| {
+        let model = Model::known(&key[idx + 1..])?;
+        Some((&key[..idx], model))
+    })
+}
+
+/// Variable → model → series. Series are aligned with the records they were built from.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ModelComparison {
+    pub variables: BTreeMap<String, HashMap<Model, Series>>,
+}
+
+impl ModelComparison {
+    #[must_use]
+    pub fn from_hourly(records: &[ForecastResultHourly]) -> Self {
+        Self::from_values(&records.iter().map(|rec| &rec.values).collect::<Vec<_>>())
+    }
+
+    #[must_use]
+    pub fn from_daily(records: &[ForecastResultDaily]) -> Self {
+        Self::from_values(&records.iter().map(|rec| &rec.values).collect::<Vec<_>>())
+    }
+
+    fn from_values(rows: &[&HashMap<String, ForecastResultItem>]) -> Self {
+        let mut variables: BTreeMap<String, HashMap<Model, Series>> = BTreeMap::new();
+
+        for (idx, values) in rows.iter().enumerate() {
+            for (key, item) in *values {
+                let Some((name, model)) = split_model_suffix(key) else {
+                    continue;
+                };
+                let series = variables
+                    .entry(name.to_string())
+                    .or_default()
+                    .entry(model)
+                    .or_insert_with(|| Series::new(name, None, vec![None; rows.len()]));
+                if series.unit.is_none() {
+                    series.unit.clone_from(&item.unit);
+                }
+                series.values[idx] = item.as_f64();
+            }
+        }
+
+        Self { variables }
+    }
+
+    /// Models returned for the variable `name`
+    #[must_use]
+    pub fn models(&self, name: &str) -> Vec<&Model> {
+        self.variables
+            .get(name)
+            .map(|models| models.keys().collect())
+            .unwrap_or_default()
+    }
+
+    #[must_use]
+    pub fn series(&self, name: &str, model: &Model) -> Option<&Series> {
+        self.variables.get(name)?.get(model)
+    }
+
+    /// Mean over the models, per step
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn mean(&self, name: &str) -> Option<Series> {
+        self.reduce(name, "mean", |values| {
+            values.iter().sum::<f64>() / values.len() as f64
+        })
+    }
+
+    /// Lowest and highest value over the models, per step
+    #[must_use]
+    pub fn envelope(&self, name: &str) -> Option<(Series, Series)> {
+        Some((
+            self.reduce(name, "min", |values| {
+                values.iter().copied().fold(f64::INFINITY, f64::min)
+            })?,
+            self.reduce(name, "max", |values| {
+                values.iter().copied().fold(f64::NEG_INFINITY, f64::max)
+            })?,
+        ))
+    }
+
+    /// Population standard deviation over the models, per step
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn spread(&self, name: &str) -> Option<Series> {
+        self.reduce(name, "spread", |values| {
+            let count = values.len() as f64;
+            let mean = values.iter().sum::<f64>() / count;
+            (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / count).sqrt()
+        })
+    }
+
+    /// Apply `f` on the non-missing values of every step, the series is named `{name}_{suffix}`
+    fn reduce(&self, name: &str, suffix: &str, f: impl Fn(&[f64]) -> f64) -> Option<Series> {
+        let models = self.variables.get(name)?;
+        let first = models.values().next()?;
+
+        let values = (0..first.values.len())
+            .map(|idx| {
+                let values: Vec<f64> = models.values().filter_map(|s| s.values[idx]).collect();
+                (!values.is_empty()).then(|| f(&values))
+            })
+            .collect();
+
+        Some(Series::new(
+            format!("{name}_{suffix}"),
+            first.unit.clone(),
+            values,
+        ))
+    }
+}
+
+impl ForecastResult {
+    /// Hourly variables grouped by model
+    #[must_use]
+    pub fn hourly_by_model(&self) -> ModelComparison {
+        ModelComparison::from_hourly(self.hourly.as_deref().unwrap_or_default())
+    }
+
+    /// Daily variables grouped by model
+    #[must_use]
+    pub fn daily_by_model(&self) -> ModelComparison {
+        ModelComparison::from_daily(self.daily.as_deref().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_keys() {
+        assert_eq!(
+            split_model_suffix("temperature_2m_icon_seamless"),
+            Some(("temperature_2m", Model::IconSeamless))
+        );
+        assert_eq!(
+            split_model_suffix("temperature_2m_max_meteofrance_arpege_world"),
+            Some(("temperature_2m_max", Model::MeteofranceArpegeWorld))
+        );
+        assert_eq!(split_model_suffix("temperature_2m"), None);
+    }
+
+    #[test]
+    fn aggregate_models() {
+        let records: Vec<ForecastResultHourly> = [[Some(1.0), Some(3.0)], [Some(2.0), None]]
+            .iter()
+            .map(|values| ForecastResultHourly {
+                values: [
+                    "temperature_2m_icon_seamless",
+                    "temperature_2m_gfs_seamless",
+                ]
+                .iter()
+                .zip(values)
+                .map(|(key, value)| {
+                    (
+                        (*key).to_string(),
+                        ForecastResultItem {
+                            unit: Some("°C".into()),
+                            value: value.map_or(serde_json::Value::Null, Into::into),
+                        },
+                    )
+                })
+                .collect(),
+                ..Default::default()
+            })
+            .collect();
+
+        let comparison = ModelComparison::from_hourly(&records);
+        assert_eq!(comparison.models("temperature_2m").len(), 2);
+        assert_eq!(
+            comparison
+                .series("temperature_2m", &Model::GfsSeamless)
+                .map(|s| s.values.clone()),
+            Some(vec![Some(3.0), None])
+        );
+        assert_eq!(
+            comparison.mean("temperature_2m").unwrap().values,
+            vec![Some(2.0), Some(2.0)]
+        );
+        let (min, max) = comparison.envelope("temperature_2m").unwrap();
+        assert_eq!(min.values, vec![Some(1.0), Some(2.0)]);
+        assert_eq!(max.values, vec![Some(3.0), Some(2.0)]);
+        assert_eq!(
+            comparison.spread("temperature_2m").unwrap().values,
+            vec![Some(1.0), Some(0.0)]
+        );
+        assert!(comparison.mean("wind_speed_10m").is_none());
+    }
+}
@@ -0,0 +1,428 @@
+//! Meteorological variables derived client-side from hourly records.
+//!
+//! Every function returns a new [`Series`] named like the matching Open-Meteo variable when one
+//! exists, `None` when a required input variable is missing. Inputs are converted to the units
+//! the formulas expect, so results requested in imperial units are supported.
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = open_meteo_rs::Client::new();
+//! let opts = open_meteo_rs::forecast::ForecastRequest::at(52.52, 13.41)
+//!     .hourly(["temperature_2m", "relative_humidity_2m"])
+//!     .build()?;
+//! let mut res = client.forecast(opts).await?;
+//!
+//! if let Some(hourly) = res.hourly.as_mut() {
+//!     if let Some(dew_point) = open_meteo_rs::derive::dew_point(hourly) {
+//!         dew_point.merge_into(hourly);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::forecast::{ForecastResultHourly, Metadata, TemperatureUnit, WindSpeedUnit};
+use crate::series::Series;
+use crate::units::Unit;
+use crate::{location, solar};
+use chrono::Datelike;
+
+const TEMPERATURE: [&str; 1] = ["temperature_2m"];
+const RELATIVE_HUMIDITY: [&str; 2] = ["relative_humidity_2m", "relativehumidity_2m"];
+const WIND_SPEED: [&str; 2] = ["wind_speed_10m", "windspeed_10m"];
+const WIND_DIRECTION: [&str; 2] = ["wind_direction_10m", "winddirection_10m"];
+const SHORTWAVE_RADIATION: [&str; 1] = ["shortwave_radiation"];
+
+const CELSIUS: Unit = Unit::Temperature(TemperatureUnit::Celsius);
+const KMH: Unit = Unit::Speed(WindSpeedUnit::Kmh);
+const MS: Unit = Unit::Speed(WindSpeedUnit::Ms);
+
+/// Dew point (Magnus formula), in °C
+#[must_use]
+pub fn dew_point(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+
+    Some(combine("dew_point_2m", "°C", &t, &rh, |t, rh| {
+        Some(dew_point_value(t, rh))
+    }))
+}
+
+/// Heat index (Rothfusz regression), in °C
+#[must_use]
+pub fn heat_index(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+
+    Some(combine("heat_index", "°C", &t, &rh, |t, rh| {
+        let f = t * 9.0 / 5.0 + 32.0;
+        let simple = 0.5 * (f + 61.0 + (f - 68.0) * 1.2 + rh * 0.094);
+
+        let hi = if f64::midpoint(simple, f) < 80.0 {
+            simple
+        } else {
+            let mut hi = -42.379 + 2.049_015_23 * f + 10.143_331_27 * rh
+                - 0.224_755_41 * f * rh
+                - 0.006_837_83 * f * f
+                - 0.054_817_17 * rh * rh
+                + 0.001_228_74 * f * f * rh
+                + 0.000_852_82 * f * rh * rh
+                - 0.000_001_99 * f * f * rh * rh;
+
+            if rh < 13.0 && (80.0..=112.0).contains(&f) {
+                hi -= (13.0 - rh) / 4.0 * ((17.0 - (f - 95.0).abs()) / 17.0).sqrt();
+            } else if rh > 85.0 && (80.0..=87.0).contains(&f) {
+                hi += (rh - 85.0) / 10.0 * (87.0 - f) / 5.0;
+            }
+            hi
+        };
+
+        Some((hi - 32.0) * 5.0 / 9.0)
+    }))
+}
+
+/// Wind chill (Environment Canada / NWS 2001 formula), in °C. Equal to the air temperature
+/// outside the validity range (above 10 °C or below 4.8 km/h).
+#[must_use]
+pub fn wind_chill(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let v = input(records, &WIND_SPEED, Some(KMH))?;
+
+    Some(combine("wind_chill", "°C", &t, &v, |t, v| {
+        if t > 10.0 || v < 4.8 {
+            return Some(t);
+        }
+
+        let v = v.powf(0.16);
+        Some(13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v)
+    }))
+}
+
+/// Humidex (Environment Canada), in °C
+#[must_use]
+pub fn humidex(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+
+    Some(combine("humidex", "°C", &t, &rh, |t, rh| {
+        let td = dew_point_value(t, rh);
+        let e = 6.11 * (5_417.753 * (1.0 / 273.16 - 1.0 / (273.15 + td))).exp();
+        Some(t + 0.5555 * (e - 10.0))
+    }))
+}
+
+/// Wet-bulb temperature (Stull 2011), in °C
+#[must_use]
+pub fn wet_bulb_temperature(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+
+    Some(combine(
+        "wet_bulb_temperature_2m",
+        "°C",
+        &t,
+        &rh,
+        |t, rh| {
+            Some(
+                t * (0.151_977 * (rh + 8.313_659).sqrt()).atan() + (t + rh).atan()
+                    - (rh - 1.676_331).atan()
+                    + 0.003_918_38 * rh.powf(1.5) * (0.023_101 * rh).atan()
+                    - 4.686_035,
+            )
+        },
+    ))
+}
+
+/// Vapour pressure deficit, in kPa
+#[must_use]
+pub fn vapour_pressure_deficit(records: &[ForecastResultHourly]) -> Option<Series> {
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+
+    Some(combine(
+        "vapour_pressure_deficit",
+        "kPa",
+        &t,
+        &rh,
+        |t, rh| {
+            let es = saturation_vapour_pressure(t);
+            Some((es - es * rh / 100.0).max(0.0))
+        },
+    ))
+}
+
+/// Eastward (u) and northward (v) wind components at 10 m, in m/s
+#[must_use]
+pub fn wind_components(records: &[ForecastResultHourly]) -> Option<(Series, Series)> {
+    let speed = input(records, &WIND_SPEED, Some(MS))?;
+    let direction = input(records, &WIND_DIRECTION, None)?;
+
+    // Direction is where the wind comes from
+    let u = combine("wind_u_component_10m", "m/s", &speed, &direction, |s, d| {
+        Some(-s * d.to_radians().sin())
+    });
+    let v = combine("wind_v_component_10m", "m/s", &speed, &direction, |s, d| {
+        Some(-s * d.to_radians().cos())
+    });
+
+    Some((u, v))
+}
+
+/// Hourly reference evapotranspiration (FAO-56 Penman-Monteith), in mm.
+///
+/// Requires `temperature_2m`, `relative_humidity_2m`, `wind_speed_10m` and
+/// `shortwave_radiation`, plus the latitude, longitude and elevation of the response metadata.
+#[must_use]
+pub fn et0_fao_evapotranspiration(
+    records: &[ForecastResultHourly],
+    metadata: &Metadata,
+) -> Option<Series> {
+    const STEFAN_BOLTZMANN_HOURLY: f64 = 2.043e-10;
+
+    let t = input(records, &TEMPERATURE, Some(CELSIUS))?;
+    let rh = input(records, &RELATIVE_HUMIDITY, None)?;
+    let wind = input(records, &WIND_SPEED, Some(MS))?;
+    let radiation = input(records, &SHORTWAVE_RADIATION, None)?;
+
+    let location = location::Location {
+        lat: metadata.latitude?,
+        lng: metadata.longitude?,
+    };
+    let elevation = f64::from(metadata.elevation.unwrap_or(0.0));
+    let suns = solar::sun_positions(records, &location, metadata.utc_offset_seconds.unwrap_or(0));
+
+    let pressure = 101.3 * ((293.0 - 0.0065 * elevation) / 293.0).powf(5.26);
+    let gamma = 0.000_665 * pressure;
+    // Wind speed from 10 m to 2 m
+    let wind_factor = 4.87 / (67.8 * 10.0 - 5.42_f64).ln();
+
+    // At night the cloudiness ratio is carried over from the last daytime hour
+    let mut cloudiness = 0.8;
+    let mut values = Vec::with_capacity(records.len());
+
+    for (idx, rec) in records.iter().enumerate() {
+        let (Some(t), Some(rh), Some(u10), Some(rs)) = (
+            t.values[idx],
+            rh.values[idx],
+            wind.values[idx],
+            radiation.values[idx],
+        ) else {
+            values.push(None);
+            continue;
+        };
+
+        let sun = suns[idx];
+        let is_day = sun.elevation > 0.0;
+        // W/m² averaged over the hour to MJ/m²/h
+        let rs = rs * 0.0036;
+
+        let day_of_year = f64::from(rec.datetime.ordinal());
+        let dr = 1.0 + 0.033 * (2.0 * std::f64::consts::PI * day_of_year / 365.0).cos();
+        let ra = 4.92 * dr * sun.elevation.to_radians().sin().max(0.0);
+        let rso = (0.75 + 2e-5 * elevation) * ra;
+        if is_day && rso > 0.0 {
+            cloudiness = (rs / rso).clamp(0.3, 1.0);
+        }
+
+        let es = saturation_vapour_pressure(t);
+        let ea = es * rh / 100.0;
+        let t_kelvin = t + 273.16;
+
+        let rns = 0.77 * rs;
+        let rnl = STEFAN_BOLTZMANN_HOURLY
+            * t_kelvin.powi(4)
+            * (0.34 - 0.14 * ea.sqrt())
+            * (1.35 * cloudiness - 0.35);
+        let rn = rns - rnl;
+        let (g, cd) = if is_day {
+            (0.1 * rn, 0.24)
+        } else {
+            (0.5 * rn, 0.96)
+        };
+
+        let u2 = u10 * wind_factor;
+        let delta = 4_098.0 * es / (t + 237.3).powi(2);
+        let et0 = (0.408 * delta * (rn - g) + gamma * 37.0 / (t + 273.0) * u2 * (es - ea))
+            / (delta + gamma * (1.0 + cd * u2));
+
+        values.push(Some(et0.max(0.0)));
+    }
+
+    Some(Series::new(
+        "et0_fao_evapotranspiration",
+        Some("mm".into()),
+        values,
+    ))
+}
+
+/// First variable of `names` found in the records, converted to `unit` if given
+fn input(records: &[ForecastResultHourly], names: &[&str], unit: Option<Unit>) -> Option<Series> {
+    names.iter().find_map(|name| match unit {
+        Some(unit) => Series::from_hourly_in(records, name, unit),
+        None => Series::from_hourly(records, name),
+    })
+}
+
+fn combine(
+    name: &str,
+    unit: &str,
+    a: &Series,
+    b: &Series,
+    f: impl Fn(f64, f64) -> Option<f64>,
+) -> Series {
+    Series::new(
+        name,
+        Some(unit.into()),
+        a.values
+            .iter()
+            .zip(&b.values)
+            .map(|(a, b)| f((*a)?, (*b)?))
+            .collect(),
+    )
+}
+
+fn dew_point_value(t: f64, rh: f64) -> f64 {
+    const A: f64 = 17.625;
+    const B: f64 = 243.04;
+
+    let gamma = (rh.max(1e-3) / 100.0).ln() + A * t / (B + t);
+    B * gamma / (A - gamma)
+}
+
+/// Saturation vapour pressure in kPa
+fn saturation_vapour_pressure(t: f64) -> f64 {
+    0.6108 * (17.27 * t / (t + 237.3)).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    fn record(values: &[(&str, &str, f64)]) -> ForecastResultHourly {
+        let mut rec = ForecastResultHourly::default();
+        for (k, unit, v) in values {
+            rec.values.insert(
+                (*k).into(),
+                ForecastResultItem {
+                    unit: Some((*unit).into()),
+                    value: (*v).into(),
+                },
+            );
+        }
+        rec
+    }
+
+    #[test]
+    fn derive_from_temperature_and_humidity() {
+        let mut records = vec![record(&[
+            ("temperature_2m", "°F", 68.0),
+            ("relative_humidity_2m", "%", 50.0),
+            ("wind_speed_10m", "km/h", 36.0),
+            ("wind_direction_10m", "°", 270.0),
+        ])];
+
+        let dew_point = dew_point(&records).unwrap();
+        assert!((dew_point.values[0].unwrap() - 9.26).abs() < 0.05);
+
+        let vpd = vapour_pressure_deficit(&records).unwrap();
+        assert!((vpd.values[0].unwrap() - 1.169).abs() < 0.01);
+
+        let (u, v) = wind_components(&records).unwrap();
+        assert!((u.values[0].unwrap() - 10.0).abs() < 1e-9);
+        assert!(v.values[0].unwrap().abs() < 1e-9);
+
+        dew_point.merge_into(&mut records);
+        assert!(records[0].value_f64("dew_point_2m").is_some());
+        assert!(humidex(&[record(&[("temperature_2m", "°C", 20.0)])]).is_none());
+    }
+
+    #[test]
+    fn wind_chill_validity_range() {
+        let records = [
+            record(&[
+                ("temperature_2m", "°C", -10.0),
+                ("wind_speed_10m", "km/h", 20.0),
+            ]),
+            record(&[
+                ("temperature_2m", "°C", 15.0),
+                ("wind_speed_10m", "km/h", 20.0),
+            ]),
+        ];
+
+        let wc = wind_chill(&records).unwrap();
+        assert!((wc.values[0].unwrap() - -17.9).abs() < 0.1);
+        assert_eq!(wc.values[1], Some(15.0));
+    }
+
+    #[test]
+    fn heat_index_matches_noaa_table() {
+        let fahrenheit = |t: f64, rh: f64| {
+            let records = [record(&[
+                ("temperature_2m", "°F", t),
+                ("relative_humidity_2m", "%", rh),
+            ])];
+            heat_index(&records).unwrap().values[0].unwrap() * 9.0 / 5.0 + 32.0
+        };
+
+        // NOAA heat index chart, rounded to the nearest degree
+        assert!((fahrenheit(90.0, 60.0) - 100.0).abs() < 1.0);
+        assert!((fahrenheit(96.0, 65.0) - 121.0).abs() < 1.0);
+        assert!((fahrenheit(80.0, 40.0) - 80.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn humidex_and_wet_bulb_reference_values() {
+        let celsius = |t: f64, rh: f64| {
+            [record(&[
+                ("temperature_2m", "°C", t),
+                ("relative_humidity_2m", "%", rh),
+            ])]
+        };
+
+        // Environment Canada humidex table
+        let hx = humidex(&celsius(30.0, 70.0)).unwrap();
+        assert!((hx.values[0].unwrap() - 41.0).abs() < 0.5);
+        let hx = humidex(&celsius(35.0, 50.0)).unwrap();
+        assert!((hx.values[0].unwrap() - 45.0).abs() < 0.5);
+
+        // Stull (2011) worked example
+        let wb = wet_bulb_temperature(&celsius(20.0, 50.0)).unwrap();
+        assert!((wb.values[0].unwrap() - 13.7).abs() < 0.05);
+    }
+
+    #[test]
+    fn et0_matches_fao56_hourly_example() {
+        // FAO-56 example 19, N'Diaye (Senegal) on 1 October, local time is UTC-1
+        let metadata = Metadata {
+            latitude: Some(16.217),
+            longitude: Some(-16.25),
+            elevation: Some(8.0),
+            utc_offset_seconds: Some(-3_600),
+            ..Default::default()
+        };
+        // Wind speeds of the example are measured at 2 m
+        let u10 = |u2: f64| u2 * (67.8_f64 * 10.0 - 5.42).ln() / 4.87;
+        let hour = |h: u32, t: f64, rh: f64, u2: f64, rs: f64| {
+            let mut rec = record(&[
+                ("temperature_2m", "°C", t),
+                ("relative_humidity_2m", "%", rh),
+                ("wind_speed_10m", "m/s", u10(u2)),
+                ("shortwave_radiation", "W/m²", rs / 0.0036),
+            ]);
+            rec.datetime = chrono::NaiveDate::from_ymd_opt(2024, 10, 1)
+                .unwrap()
+                .and_hms_opt(h, 0, 0)
+                .unwrap();
+            rec
+        };
+
+        // 14:00-15:00, 0.63 mm/h
+        let et0 = et0_fao_evapotranspiration(&[hour(15, 38.0, 52.0, 3.3, 2.45)], &metadata);
+        assert!((et0.unwrap().values[0].unwrap() - 0.63).abs() < 0.05);
+
+        // 02:00-03:00, 0.0 mm/h
+        let et0 = et0_fao_evapotranspiration(&[hour(3, 28.0, 90.0, 1.9, 0.0)], &metadata);
+        assert!(et0.unwrap().values[0].unwrap() < 0.01);
+    }
+}
@@ -4,6 +4,9 @@ use snafu::prelude::*;
 pub enum ClientError {
     #[snafu(display("The status code '{status_code}' was returned: {text}"))]
     InvalidResponseStatus { status_code: u16, text: String },
+
+    #[snafu(display("No place found for '{name}'"))]
+    PlaceNotFound { name: String },
 }
 
 #[derive(Debug, Snafu)]
@@ -25,4 +28,84 @@ pub enum ConversionError {
 
     #[snafu(display("Invalid cell selection '{selection}'"))]
     InvalidCellSelection { selection: String },
+
+    #[snafu(display("Invalid tilt '{tilt}', expected a value between 0 and 90"))]
+    InvalidTilt { tilt: String },
+
+    #[snafu(display("Invalid azimuth '{azimuth}', expected a value between -180 and 180"))]
+    InvalidAzimuth { azimuth: String },
+
+    #[snafu(display("Invalid pressure level '{level}'"))]
+    InvalidPressureLevel { level: String },
+
+    #[snafu(display("Invalid pressure variable '{variable}'"))]
+    InvalidPressureVariable { variable: String },
+
+    #[snafu(display("Invalid weather code '{code}'"))]
+    InvalidWeatherCode { code: String },
+
+    #[snafu(display("Invalid unit '{unit}'"))]
+    InvalidUnit { unit: String },
+
+    #[snafu(display("Invalid feature code '{code}'"))]
+    InvalidFeatureCode { code: String },
+
+    #[snafu(display("Invalid GeoNames record at line {line}"))]
+    InvalidGeoNamesRecord { line: usize },
+
+    #[snafu(display("Invalid pollutant '{pollutant}'"))]
+    InvalidPollutant { pollutant: String },
+
+    #[snafu(display("Invalid pollen species '{species}'"))]
+    InvalidPollenSpecies { species: String },
+
+    #[snafu(display("Invalid air quality domain '{domain}'"))]
+    InvalidAirQualityDomain { domain: String },
+
+    #[snafu(display("Invalid query string '{query}'"))]
+    InvalidQueryString { query: String },
+
+    #[snafu(display("Invalid value '{value}' for parameter '{name}'"))]
+    InvalidParameter { name: String, value: String },
+
+    #[snafu(display("Missing query parameter '{name}'"))]
+    MissingQueryParameter { name: String },
+}
+
+#[derive(Debug, Snafu)]
+pub enum ValidationError {
+    #[snafu(display("'{first}' and '{second}' are mutually exclusive"))]
+    MutuallyExclusive { first: String, second: String },
+
+    #[snafu(display("'{name}' is out of range: '{value}' is not between {min} and {max}"))]
+    OutOfRange {
+        name: String,
+        value: String,
+        min: String,
+        max: String,
+    },
+
+    #[snafu(display("'{name}' requires '{required}' to be set"))]
+    MissingParameter { name: String, required: String },
+
+    #[snafu(display("Start date '{start_date}' is after end date '{end_date}'"))]
+    InvalidDateRange {
+        start_date: chrono::NaiveDate,
+        end_date: chrono::NaiveDate,
+    },
+
+    #[snafu(display("'{start_name}' ({start_hour}) is after '{end_name}' ({end_hour})"))]
+    InvalidHourRange {
+        start_name: String,
+        start_hour: chrono::NaiveDateTime,
+        end_name: String,
+        end_hour: chrono::NaiveDateTime,
+    },
+
+    #[snafu(display("Model '{model}' is not supported by the {api} API"))]
+    UnsupportedModel { model: String, api: String },
+
+    /// `by` is the API or the model lacking the section
+    #[snafu(display("Section '{section}' is not supported by {by}"))]
+    UnsupportedSection { section: String, by: String },
 }
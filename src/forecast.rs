@@ -1,11 +1,30 @@
-use super::{client, errors, forecast, location};
+use super::{builder, client, errors, forecast, location, models, query, validation};
 use chrono::TimeZone;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt::Display;
 
-#[derive(Debug, Clone)]
+pub use super::models::Model;
+
+/// Maximum value of `forecast_days`
+pub const MAX_FORECAST_DAYS: u8 = 16;
+/// Maximum value of `past_days`
+pub const MAX_PAST_DAYS: u8 = 92;
+/// Maximum value of `forecast_minutely_15`
+pub const MAX_FORECAST_MINUTELY_15: u16 = 1536;
+/// Maximum value of `past_minutely_15`
+pub const MAX_PAST_MINUTELY_15: u16 = MAX_PAST_DAYS as u16 * 96;
+/// First day available in the archive (ERA5)
+pub const ARCHIVE_FIRST_DATE: chrono::NaiveDate = match chrono::NaiveDate::from_ymd_opt(1940, 1, 1)
+{
+    Some(date) => date,
+    None => panic!("invalid archive first date"),
+};
+/// Delay in days before ERA5 data is available in the archive
+pub const ARCHIVE_DELAY_DAYS: i64 = 5;
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Elevation {
     Nan,
     Value(f32),
@@ -34,6 +53,10 @@ impl TryFrom<&str> for Elevation {
             return Ok(Self::Nan);
         }
 
+        if let Ok(v) = value.parse::<f32>() {
+            return Ok(Self::Value(v));
+        }
+
         Err(errors::ConversionError::InvalidElevation {
             elevation: value.to_string(),
         })
@@ -46,7 +69,33 @@ impl From<f32> for Elevation {
     }
 }
 
-#[derive(Debug, Clone)]
+impl Serialize for Elevation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Nan => serializer.serialize_str("nan"),
+            Self::Value(v) => serializer.serialize_f32(*v),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Elevation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Value(f32),
+            Text(String),
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Value(v) => Ok(Self::Value(v)),
+            Repr::Text(v) => Self::try_from(v.as_str()).map_err(serde::de::Error::custom),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum TemperatureUnit {
     Celsius,
     Fahrenheit,
@@ -81,7 +130,16 @@ impl TryFrom<&str> for TemperatureUnit {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<String> for TemperatureUnit {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum WindSpeedUnit {
     Kmh,
     Ms,
@@ -123,7 +181,16 @@ impl TryFrom<&str> for WindSpeedUnit {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<String> for WindSpeedUnit {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum PrecipitationUnit {
     Millimeters,
     Inches,
@@ -159,438 +226,16 @@ impl TryFrom<&str> for PrecipitationUnit {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum Model {
-    Undefined,
-    BestMatch,
-    GfsSeamless,
-    GfsGlobal,
-    GfsHrrr,
-    MeteofranceSeamless,
-    MeteofranceArpegeSeamless,
-    MeteofranceArpegeWorld,
-    MeteofranceArpegeEurope,
-    MeteofranceAromeSeamless,
-    MeteofranceAromeFrance,
-    MeteofranceAromeFranceHd,
-    JmaSeamless,
-    JmaMsm,
-    JmsGsm,
-    JmaGsm,
-    GemSeamless,
-    GemGlobal,
-    GemRegional,
-    GemHrdpsContinental,
-    IconSeamless,
-    IconGlobal,
-    IconEu,
-    IconD2,
-    EcmwfIfs04,
-    MetnoNordic,
-    Era5Seamless,
-    Era5,
-    Cerra,
-    Era5Land,
-    EcmwfIfs,
-    Gwam,
-    Ewam,
-    GlofasSeamlessV3,
-    GlofasForecastV3,
-    GlofasConsolidatedV3,
-    GlofasSeamlessV4,
-    GlofasForecastV4,
-    GlofasConsolidatedV4,
-    Gfs025,
-    Gfs05,
-    CMCCCM2VHR4,
-    FGOALSF3HHighressst,
-    FGOALSF3H,
-    HiramSITHR,
-    MRIAGCM32S,
-    ECEarth3pHR,
-    MPIESM12XR,
-    NICAM168S,
-    CamsEurope,
-    CamsGlobal,
-    Cfsv2,
-    Era5Ocean,
-    CmaGrapesGlobal,
-    BomAccessGlobal,
-    BomAccessGlobalEnsemble,
-    ArpaeCosmoSeamless,
-    ArpaeCosmo2i,
-    ArpaeCosmo2iRuc,
-    ArpaeCosmo5m,
-    EcmwfIfs025,
-    EcmwfAifs025,
-    Gfs013,
-    GfsGraphcast025,
-    EcmwfWam025,
-    MeteofranceWave,
-    MeteofranceCurrents,
-    EcmwfWam025Ensemble,
-    NcepGfswave025,
-    NcepGefswave025,
-    KnmiSeamless,
-    KnmiHarmonieAromeEurope,
-    KnmiHarmonieAromeNetherlands,
-    DmiSeamless,
-    DmiHarmonieAromeEurope,
-    MetnoSeamless,
-    Era5Ensemble,
-    EcmwfIfsAnalysis,
-    EcmwfIfsLongWindow,
-    EcmwfIfsAnalysisLongWindow,
-    UkmoGlobalDeterministic10km,
-    UkmoUkDeterministic2km,
-    UkmoSeamless,
-    NcepGfswave016,
-    NcepNbmConus,
-    UkmoGlobalEnsemble20km,
-    EcmwfAifs025Single,
-    JmaJaxaHimawari,
-    EumetsatSarah3,
-    EumetsatLsaSafMsg,
-    EumetsatLsaSafIodc,
-    SatelliteRadiationSeamless,
-    KmaGdps,
-    KmaLdps,
-    KmaSeamless,
-    ItaliaMeteoArpaeIcon2i,
-    UkmoUkEnsemble2km,
-    MeteofranceAromeFranceHd15min,
-    MeteofranceAromeFrance15min,
-    MeteoswissIconCh1,
-    MeteoswissIconCh2,
-    MeteoswissIconCh1Ensemble,
-    MeteoswissIconCh2Ensemble,
-    MeteoswissIconSeamless,
-    NcepNamConus,
-    IconD2Ruc,
-    EcmwfSeas5,
-    EcmwfEc46,
-    EcmwfSeasonalSeamless,
-    EcmwfIfsSeamless,
-    JmaJaxaMtgFci,
-    GemHrdpsWest,
-    EcmwfWam,
-    NcepAigfs025,
-    NcepAigefs025,
-    NcepHgefs025EnsembleMean,
-    EcmwfSeasonalEnsembleMeanSeamless,
-    EcmwfSeas5EnsembleMean,
-    EcmwfEc46EnsembleMean,
-    NcepAigefs025EnsembleMean,
-    DwdIconEpsEnsembleMeanSeamless,
-    DwdIconEpsEnsembleMean,
-    DwdIconEuEpsEnsembleMean,
-    DwdIconD2EpsEnsembleMean,
-    NcepGefsEnsembleMeanSeamless,
-    NcepGefs025EnsembleMean,
-    NcepGefs05EnsembleMean,
-    EcmwfIfs025EnsembleMean,
-    EcmwfAifs025EnsembleMean,
-    MeteoswissIconCh1EnsembleMean,
-    MeteoswissIconCh2EnsembleMean,
-    CmcGemGepsEnsembleMean,
-    UkmoGlobalEnsembleMean20km,
-    UkmoUkEnsembleMean2km,
-}
-
-impl std::fmt::Display for Model {
-    #[allow(clippy::too_many_lines)]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            Self::Undefined => write!(f, "undefined"),
-            Self::BestMatch => write!(f, "best_match"),
-            Self::GfsSeamless => write!(f, "gfs_seamless"),
-            Self::GfsGlobal => write!(f, "gfs_global"),
-            Self::GfsHrrr => write!(f, "gfs_hrrr"),
-            Self::MeteofranceSeamless => write!(f, "meteofrance_seamless"),
-            Self::MeteofranceArpegeSeamless => write!(f, "meteofrance_arpege_seamless"),
-            Self::MeteofranceArpegeWorld => write!(f, "meteofrance_arpege_world"),
-            Self::MeteofranceArpegeEurope => write!(f, "meteofrance_arpege_europe"),
-            Self::MeteofranceAromeSeamless => write!(f, "meteofrance_arome_seamless"),
-            Self::MeteofranceAromeFrance => write!(f, "meteofrance_arome_france"),
-            Self::MeteofranceAromeFranceHd => write!(f, "meteofrance_arome_france_hd"),
-            Self::JmaSeamless => write!(f, "jma_seamless"),
-            Self::JmaMsm => write!(f, "jma_msm"),
-            Self::JmsGsm => write!(f, "jms_gsm"),
-            Self::JmaGsm => write!(f, "jma_gsm"),
-            Self::GemSeamless => write!(f, "gem_seamless"),
-            Self::GemGlobal => write!(f, "gem_global"),
-            Self::GemRegional => write!(f, "gem_regional"),
-            Self::GemHrdpsContinental => write!(f, "gem_hrdps_continental"),
-            Self::IconSeamless => write!(f, "icon_seamless"),
-            Self::IconGlobal => write!(f, "icon_global"),
-            Self::IconEu => write!(f, "icon_eu"),
-            Self::IconD2 => write!(f, "icon_d2"),
-            Self::EcmwfIfs04 => write!(f, "ecmwf_ifs04"),
-            Self::MetnoNordic => write!(f, "metno_nordic"),
-            Self::Era5Seamless => write!(f, "era5_seamless"),
-            Self::Era5 => write!(f, "era5"),
-            Self::Cerra => write!(f, "cerra"),
-            Self::Era5Land => write!(f, "era5_land"),
-            Self::EcmwfIfs => write!(f, "ecmwf_ifs"),
-            Self::Gwam => write!(f, "gwam"),
-            Self::Ewam => write!(f, "ewam"),
-            Self::GlofasSeamlessV3 => write!(f, "glofas_seamless_v3"),
-            Self::GlofasForecastV3 => write!(f, "glofas_forecast_v3"),
-            Self::GlofasConsolidatedV3 => write!(f, "glofas_consolidated_v3"),
-            Self::GlofasSeamlessV4 => write!(f, "glofas_seamless_v4"),
-            Self::GlofasForecastV4 => write!(f, "glofas_forecast_v4"),
-            Self::GlofasConsolidatedV4 => write!(f, "glofas_consolidated_v4"),
-            Self::Gfs025 => write!(f, "gfs025"),
-            Self::Gfs05 => write!(f, "gfs05"),
-            Self::CMCCCM2VHR4 => write!(f, "CMCC_CM2_VHR4"),
-            Self::FGOALSF3HHighressst => write!(f, "FGOALS_f3_H_highresSST"),
-            Self::FGOALSF3H => write!(f, "FGOALS_f3_H"),
-            Self::HiramSITHR => write!(f, "HiRAM_SIT_HR"),
-            Self::MRIAGCM32S => write!(f, "MRI_AGCM3_2_S"),
-            Self::ECEarth3pHR => write!(f, "EC_Earth3P_HR"),
-            Self::MPIESM12XR => write!(f, "MPI_ESM1_2_XR"),
-            Self::NICAM168S => write!(f, "NICAM16_8S"),
-            Self::CamsEurope => write!(f, "cams_europe"),
-            Self::CamsGlobal => write!(f, "cams_global"),
-            Self::Cfsv2 => write!(f, "cfsv2"),
-            Self::Era5Ocean => write!(f, "era5_ocean"),
-            Self::CmaGrapesGlobal => write!(f, "cma_grapes_global"),
-            Self::BomAccessGlobal => write!(f, "bom_access_global"),
-            Self::BomAccessGlobalEnsemble => write!(f, "bom_access_global_ensemble"),
-            Self::ArpaeCosmoSeamless => write!(f, "arpae_cosmo_seamless"),
-            Self::ArpaeCosmo2i => write!(f, "arpae_cosmo_2i"),
-            Self::ArpaeCosmo2iRuc => write!(f, "arpae_cosmo_2i_ruc"),
-            Self::ArpaeCosmo5m => write!(f, "arpae_cosmo_5m"),
-            Self::EcmwfIfs025 => write!(f, "ecmwf_ifs025"),
-            Self::EcmwfAifs025 => write!(f, "ecmwf_aifs025"),
-            Self::Gfs013 => write!(f, "gfs013"),
-            Self::GfsGraphcast025 => write!(f, "gfs_graphcast025"),
-            Self::EcmwfWam025 => write!(f, "ecmwf_wam025"),
-            Self::MeteofranceWave => write!(f, "meteofrance_wave"),
-            Self::MeteofranceCurrents => write!(f, "meteofrance_currents"),
-            Self::EcmwfWam025Ensemble => write!(f, "ecmwf_wam025_ensemble"),
-            Self::NcepGfswave025 => write!(f, "ncep_gfswave025"),
-            Self::NcepGefswave025 => write!(f, "ncep_gefswave025"),
-            Self::KnmiSeamless => write!(f, "knmi_seamless"),
-            Self::KnmiHarmonieAromeEurope => write!(f, "knmi_harmonie_arome_europe"),
-            Self::KnmiHarmonieAromeNetherlands => write!(f, "knmi_harmonie_arome_netherlands"),
-            Self::DmiSeamless => write!(f, "dmi_seamless"),
-            Self::DmiHarmonieAromeEurope => write!(f, "dmi_harmonie_arome_europe"),
-            Self::MetnoSeamless => write!(f, "metno_seamless"),
-            Self::Era5Ensemble => write!(f, "era5_ensemble"),
-            Self::EcmwfIfsAnalysis => write!(f, "ecmwf_ifs_analysis"),
-            Self::EcmwfIfsLongWindow => write!(f, "ecmwf_ifs_long_window"),
-            Self::EcmwfIfsAnalysisLongWindow => write!(f, "ecmwf_ifs_analysis_long_window"),
-            Self::UkmoGlobalDeterministic10km => write!(f, "ukmo_global_deterministic_10km"),
-            Self::UkmoUkDeterministic2km => write!(f, "ukmo_uk_deterministic_2km"),
-            Self::UkmoSeamless => write!(f, "ukmo_seamless"),
-            Self::NcepGfswave016 => write!(f, "ncep_gfswave016"),
-            Self::NcepNbmConus => write!(f, "ncep_nbm_conus"),
-            Self::UkmoGlobalEnsemble20km => write!(f, "ukmo_global_ensemble_20km"),
-            Self::EcmwfAifs025Single => write!(f, "ecmwf_aifs025_single"),
-            Self::JmaJaxaHimawari => write!(f, "jma_jaxa_himawari"),
-            Self::EumetsatSarah3 => write!(f, "eumetsat_sarah3"),
-            Self::EumetsatLsaSafMsg => write!(f, "eumetsat_lsa_saf_msg"),
-            Self::EumetsatLsaSafIodc => write!(f, "eumetsat_lsa_saf_iodc"),
-            Self::SatelliteRadiationSeamless => write!(f, "satellite_radiation_seamless"),
-            Self::KmaGdps => write!(f, "kma_gdps"),
-            Self::KmaLdps => write!(f, "kma_ldps"),
-            Self::KmaSeamless => write!(f, "kma_seamless"),
-            Self::ItaliaMeteoArpaeIcon2i => write!(f, "italia_meteo_arpae_icon_2i"),
-            Self::UkmoUkEnsemble2km => write!(f, "ukmo_uk_ensemble_2km"),
-            Self::MeteofranceAromeFranceHd15min => write!(f, "meteofrance_arome_france_hd_15min"),
-            Self::MeteofranceAromeFrance15min => write!(f, "meteofrance_arome_france_15min"),
-            Self::MeteoswissIconCh1 => write!(f, "meteoswiss_icon_ch1"),
-            Self::MeteoswissIconCh2 => write!(f, "meteoswiss_icon_ch2"),
-            Self::MeteoswissIconCh1Ensemble => write!(f, "meteoswiss_icon_ch1_ensemble"),
-            Self::MeteoswissIconCh2Ensemble => write!(f, "meteoswiss_icon_ch2_ensemble"),
-            Self::MeteoswissIconSeamless => write!(f, "meteoswiss_icon_seamless"),
-            Self::NcepNamConus => write!(f, "ncep_nam_conus"),
-            Self::IconD2Ruc => write!(f, "icon_d2_ruc"),
-            Self::EcmwfSeas5 => write!(f, "ecmwf_seas5"),
-            Self::EcmwfEc46 => write!(f, "ecmwf_ec46"),
-            Self::EcmwfSeasonalSeamless => write!(f, "ecmwf_seasonal_seamless"),
-            Self::EcmwfIfsSeamless => write!(f, "ecmwf_ifs_seamless"),
-            Self::JmaJaxaMtgFci => write!(f, "jma_jaxa_mtg_fci"),
-            Self::GemHrdpsWest => write!(f, "gem_hrdps_west"),
-            Self::EcmwfWam => write!(f, "ecmwf_wam"),
-            Self::NcepAigfs025 => write!(f, "ncep_aigfs025"),
-            Self::NcepAigefs025 => write!(f, "ncep_aigefs025"),
-            Self::NcepHgefs025EnsembleMean => write!(f, "ncep_hgefs025_ensemble_mean"),
-            Self::EcmwfSeasonalEnsembleMeanSeamless => {
-                write!(f, "ecmwf_seasonal_ensemble_mean_seamless")
-            }
-            Self::EcmwfSeas5EnsembleMean => write!(f, "ecmwf_seas5_ensemble_mean"),
-            Self::EcmwfEc46EnsembleMean => write!(f, "ecmwf_ec46_ensemble_mean"),
-            Self::NcepAigefs025EnsembleMean => write!(f, "ncep_aigefs025_ensemble_mean"),
-            Self::DwdIconEpsEnsembleMeanSeamless => {
-                write!(f, "dwd_icon_eps_ensemble_mean_seamless")
-            }
-            Self::DwdIconEpsEnsembleMean => write!(f, "dwd_icon_eps_ensemble_mean"),
-            Self::DwdIconEuEpsEnsembleMean => write!(f, "dwd_icon_eu_eps_ensemble_mean"),
-            Self::DwdIconD2EpsEnsembleMean => write!(f, "dwd_icon_d2_eps_ensemble_mean"),
-            Self::NcepGefsEnsembleMeanSeamless => write!(f, "ncep_gefs_ensemble_mean_seamless"),
-            Self::NcepGefs025EnsembleMean => write!(f, "ncep_gefs025_ensemble_mean"),
-            Self::NcepGefs05EnsembleMean => write!(f, "ncep_gefs05_ensemble_mean"),
-            Self::EcmwfIfs025EnsembleMean => write!(f, "ecmwf_ifs025_ensemble_mean"),
-            Self::EcmwfAifs025EnsembleMean => write!(f, "ecmwf_aifs025_ensemble_mean"),
-            Self::MeteoswissIconCh1EnsembleMean => write!(f, "meteoswiss_icon_ch1_ensemble_mean"),
-            Self::MeteoswissIconCh2EnsembleMean => write!(f, "meteoswiss_icon_ch2_ensemble_mean"),
-            Self::CmcGemGepsEnsembleMean => write!(f, "cmc_gem_geps_ensemble_mean"),
-            Self::UkmoGlobalEnsembleMean20km => write!(f, "ukmo_global_ensemble_mean_20km"),
-            Self::UkmoUkEnsembleMean2km => write!(f, "ukmo_uk_ensemble_mean_2km"),
-        }
-    }
-}
-
-impl TryFrom<&str> for Model {
+impl TryFrom<String> for PrecipitationUnit {
     type Error = errors::ConversionError;
 
-    #[allow(clippy::too_many_lines)]
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        match value {
-            "undefined" => Ok(Self::Undefined),
-            "best_match" => Ok(Self::BestMatch),
-            "gfs_seamless" => Ok(Self::GfsSeamless),
-            "gfs_global" => Ok(Self::GfsGlobal),
-            "gfs_hrrr" => Ok(Self::GfsHrrr),
-            "meteofrance_seamless" => Ok(Self::MeteofranceSeamless),
-            "meteofrance_arpege_seamless" => Ok(Self::MeteofranceArpegeSeamless),
-            "meteofrance_arpege_world" => Ok(Self::MeteofranceArpegeWorld),
-            "meteofrance_arpege_europe" => Ok(Self::MeteofranceArpegeEurope),
-            "meteofrance_arome_seamless" => Ok(Self::MeteofranceAromeSeamless),
-            "meteofrance_arome_france" => Ok(Self::MeteofranceAromeFrance),
-            "meteofrance_arome_france_hd" => Ok(Self::MeteofranceAromeFranceHd),
-            "jma_seamless" => Ok(Self::JmaSeamless),
-            "jma_msm" => Ok(Self::JmaMsm),
-            "jms_gsm" => Ok(Self::JmsGsm),
-            "jma_gsm" => Ok(Self::JmaGsm),
-            "gem_seamless" => Ok(Self::GemSeamless),
-            "gem_global" => Ok(Self::GemGlobal),
-            "gem_regional" => Ok(Self::GemRegional),
-            "gem_hrdps_continental" => Ok(Self::GemHrdpsContinental),
-            "icon_seamless" => Ok(Self::IconSeamless),
-            "icon_global" => Ok(Self::IconGlobal),
-            "icon_eu" => Ok(Self::IconEu),
-            "icon_d2" => Ok(Self::IconD2),
-            "ecmwf_ifs04" => Ok(Self::EcmwfIfs04),
-            "metno_nordic" => Ok(Self::MetnoNordic),
-            "era5_seamless" => Ok(Self::Era5Seamless),
-            "era5" => Ok(Self::Era5),
-            "cerra" => Ok(Self::Cerra),
-            "era5_land" => Ok(Self::Era5Land),
-            "ecmwf_ifs" => Ok(Self::EcmwfIfs),
-            "gwam" => Ok(Self::Gwam),
-            "ewam" => Ok(Self::Ewam),
-            "glofas_seamless_v3" => Ok(Self::GlofasSeamlessV3),
-            "glofas_forecast_v3" => Ok(Self::GlofasForecastV3),
-            "glofas_consolidated_v3" => Ok(Self::GlofasConsolidatedV3),
-            "glofas_seamless_v4" => Ok(Self::GlofasSeamlessV4),
-            "glofas_forecast_v4" => Ok(Self::GlofasForecastV4),
-            "glofas_consolidated_v4" => Ok(Self::GlofasConsolidatedV4),
-            "gfs025" => Ok(Self::Gfs025),
-            "gfs05" => Ok(Self::Gfs05),
-            "CMCC_CM2_VHR4" => Ok(Self::CMCCCM2VHR4),
-            "FGOALS_f3_H_highresSST" => Ok(Self::FGOALSF3HHighressst),
-            "FGOALS_f3_H" => Ok(Self::FGOALSF3H),
-            "HiRAM_SIT_HR" => Ok(Self::HiramSITHR),
-            "MRI_AGCM3_2_S" => Ok(Self::MRIAGCM32S),
-            "EC_Earth3P_HR" => Ok(Self::ECEarth3pHR),
-            "MPI_ESM1_2_XR" => Ok(Self::MPIESM12XR),
-            "NICAM16_8S" => Ok(Self::NICAM168S),
-            "cams_europe" => Ok(Self::CamsEurope),
-            "cams_global" => Ok(Self::CamsGlobal),
-            "cfsv2" => Ok(Self::Cfsv2),
-            "era5_ocean" => Ok(Self::Era5Ocean),
-            "cma_grapes_global" => Ok(Self::CmaGrapesGlobal),
-            "bom_access_global" => Ok(Self::BomAccessGlobal),
-            "bom_access_global_ensemble" => Ok(Self::BomAccessGlobalEnsemble),
-            "arpae_cosmo_seamless" => Ok(Self::ArpaeCosmoSeamless),
-            "arpae_cosmo_2i" => Ok(Self::ArpaeCosmo2i),
-            "arpae_cosmo_2i_ruc" => Ok(Self::ArpaeCosmo2iRuc),
-            "arpae_cosmo_5m" => Ok(Self::ArpaeCosmo5m),
-            "ecmwf_ifs025" => Ok(Self::EcmwfIfs025),
-            "ecmwf_aifs025" => Ok(Self::EcmwfAifs025),
-            "gfs013" => Ok(Self::Gfs013),
-            "gfs_graphcast025" => Ok(Self::GfsGraphcast025),
-            "ecmwf_wam025" => Ok(Self::EcmwfWam025),
-            "meteofrance_wave" => Ok(Self::MeteofranceWave),
-            "meteofrance_currents" => Ok(Self::MeteofranceCurrents),
-            "ecmwf_wam025_ensemble" => Ok(Self::EcmwfWam025Ensemble),
-            "ncep_gfswave025" => Ok(Self::NcepGfswave025),
-            "ncep_gefswave025" => Ok(Self::NcepGefswave025),
-            "knmi_seamless" => Ok(Self::KnmiSeamless),
-            "knmi_harmonie_arome_europe" => Ok(Self::KnmiHarmonieAromeEurope),
-            "knmi_harmonie_arome_netherlands" => Ok(Self::KnmiHarmonieAromeNetherlands),
-            "dmi_seamless" => Ok(Self::DmiSeamless),
-            "dmi_harmonie_arome_europe" => Ok(Self::DmiHarmonieAromeEurope),
-            "metno_seamless" => Ok(Self::MetnoSeamless),
-            "era5_ensemble" => Ok(Self::Era5Ensemble),
-            "ecmwf_ifs_analysis" => Ok(Self::EcmwfIfsAnalysis),
-            "ecmwf_ifs_long_window" => Ok(Self::EcmwfIfsLongWindow),
-            "ecmwf_ifs_analysis_long_window" => Ok(Self::EcmwfIfsAnalysisLongWindow),
-            "ukmo_global_deterministic_10km" => Ok(Self::UkmoGlobalDeterministic10km),
-            "ukmo_uk_deterministic_2km" => Ok(Self::UkmoUkDeterministic2km),
-            "ukmo_seamless" => Ok(Self::UkmoSeamless),
-            "ncep_gfswave016" => Ok(Self::NcepGfswave016),
-            "ncep_nbm_conus" => Ok(Self::NcepNbmConus),
-            "ukmo_global_ensemble_20km" => Ok(Self::UkmoGlobalEnsemble20km),
-            "ecmwf_aifs025_single" => Ok(Self::EcmwfAifs025Single),
-            "jma_jaxa_himawari" => Ok(Self::JmaJaxaHimawari),
-            "eumetsat_sarah3" => Ok(Self::EumetsatSarah3),
-            "eumetsat_lsa_saf_msg" => Ok(Self::EumetsatLsaSafMsg),
-            "eumetsat_lsa_saf_iodc" => Ok(Self::EumetsatLsaSafIodc),
-            "satellite_radiation_seamless" => Ok(Self::SatelliteRadiationSeamless),
-            "kma_gdps" => Ok(Self::KmaGdps),
-            "kma_ldps" => Ok(Self::KmaLdps),
-            "kma_seamless" => Ok(Self::KmaSeamless),
-            "italia_meteo_arpae_icon_2i" => Ok(Self::ItaliaMeteoArpaeIcon2i),
-            "ukmo_uk_ensemble_2km" => Ok(Self::UkmoUkEnsemble2km),
-            "meteofrance_arome_france_hd_15min" => Ok(Self::MeteofranceAromeFranceHd15min),
-            "meteofrance_arome_france_15min" => Ok(Self::MeteofranceAromeFrance15min),
-            "meteoswiss_icon_ch1" => Ok(Self::MeteoswissIconCh1),
-            "meteoswiss_icon_ch2" => Ok(Self::MeteoswissIconCh2),
-            "meteoswiss_icon_ch1_ensemble" => Ok(Self::MeteoswissIconCh1Ensemble),
-            "meteoswiss_icon_ch2_ensemble" => Ok(Self::MeteoswissIconCh2Ensemble),
-            "meteoswiss_icon_seamless" => Ok(Self::MeteoswissIconSeamless),
-            "ncep_nam_conus" => Ok(Self::NcepNamConus),
-            "icon_d2_ruc" => Ok(Self::IconD2Ruc),
-            "ecmwf_seas5" => Ok(Self::EcmwfSeas5),
-            "ecmwf_ec46" => Ok(Self::EcmwfEc46),
-            "ecmwf_seasonal_seamless" => Ok(Self::EcmwfSeasonalSeamless),
-            "ecmwf_ifs_seamless" => Ok(Self::EcmwfIfsSeamless),
-            "jma_jaxa_mtg_fci" => Ok(Self::JmaJaxaMtgFci),
-            "gem_hrdps_west" => Ok(Self::GemHrdpsWest),
-            "ecmwf_wam" => Ok(Self::EcmwfWam),
-            "ncep_aigfs025" => Ok(Self::NcepAigfs025),
-            "ncep_aigefs025" => Ok(Self::NcepAigefs025),
-            "ncep_hgefs025_ensemble_mean" => Ok(Self::NcepHgefs025EnsembleMean),
-            "ecmwf_seasonal_ensemble_mean_seamless" => Ok(Self::EcmwfSeasonalEnsembleMeanSeamless),
-            "ecmwf_seas5_ensemble_mean" => Ok(Self::EcmwfSeas5EnsembleMean),
-            "ecmwf_ec46_ensemble_mean" => Ok(Self::EcmwfEc46EnsembleMean),
-            "ncep_aigefs025_ensemble_mean" => Ok(Self::NcepAigefs025EnsembleMean),
-            "dwd_icon_eps_ensemble_mean_seamless" => Ok(Self::DwdIconEpsEnsembleMeanSeamless),
-            "dwd_icon_eps_ensemble_mean" => Ok(Self::DwdIconEpsEnsembleMean),
-            "dwd_icon_eu_eps_ensemble_mean" => Ok(Self::DwdIconEuEpsEnsembleMean),
-            "dwd_icon_d2_eps_ensemble_mean" => Ok(Self::DwdIconD2EpsEnsembleMean),
-            "ncep_gefs_ensemble_mean_seamless" => Ok(Self::NcepGefsEnsembleMeanSeamless),
-            "ncep_gefs025_ensemble_mean" => Ok(Self::NcepGefs025EnsembleMean),
-            "ncep_gefs05_ensemble_mean" => Ok(Self::NcepGefs05EnsembleMean),
-            "ecmwf_ifs025_ensemble_mean" => Ok(Self::EcmwfIfs025EnsembleMean),
-            "ecmwf_aifs025_ensemble_mean" => Ok(Self::EcmwfAifs025EnsembleMean),
-            "meteoswiss_icon_ch1_ensemble_mean" => Ok(Self::MeteoswissIconCh1EnsembleMean),
-            "meteoswiss_icon_ch2_ensemble_mean" => Ok(Self::MeteoswissIconCh2EnsembleMean),
-            "cmc_gem_geps_ensemble_mean" => Ok(Self::CmcGemGepsEnsembleMean),
-            "ukmo_global_ensemble_mean_20km" => Ok(Self::UkmoGlobalEnsembleMean20km),
-            "ukmo_uk_ensemble_mean_2km" => Ok(Self::UkmoUkEnsembleMean2km),
-            _ => Err(errors::ConversionError::InvalidModel {
-                model: value.to_string(),
-            }),
-        }
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub enum CellSelection {
     Land,
     Sea,
@@ -628,7 +273,104 @@ impl TryFrom<&str> for CellSelection {
     }
 }
 
-#[derive(Debug, Clone)]
+impl TryFrom<String> for CellSelection {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Tilt of a solar panel in degrees, from 0 (horizontal) to 90 (vertical).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f32", into = "f32")]
+pub struct Tilt(f32);
+
+impl Tilt {
+    #[must_use]
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Display for Tilt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Tilt> for String {
+    fn from(value: Tilt) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<Tilt> for f32 {
+    fn from(value: Tilt) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<f32> for Tilt {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if (0.0..=90.0).contains(&value) {
+            return Ok(Self(value));
+        }
+
+        Err(errors::ConversionError::InvalidTilt {
+            tilt: value.to_string(),
+        })
+    }
+}
+
+/// Azimuth of a solar panel in degrees. 0 is south, -90 is east, 90 is west and ±180 is north.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "f32", into = "f32")]
+pub struct Azimuth(f32);
+
+impl Azimuth {
+    #[must_use]
+    pub fn value(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Display for Azimuth {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Azimuth> for String {
+    fn from(value: Azimuth) -> Self {
+        value.to_string()
+    }
+}
+
+impl From<Azimuth> for f32 {
+    fn from(value: Azimuth) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<f32> for Azimuth {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        if (-180.0..=180.0).contains(&value) {
+            return Ok(Self(value));
+        }
+
+        Err(errors::ConversionError::InvalidAzimuth {
+            azimuth: value.to_string(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Options {
     pub location: location::Location,
     pub elevation: Option<Elevation>,
@@ -648,10 +390,25 @@ pub struct Options {
     pub forecast_days: Option<u8>,
     // max minutely_15 data points is 1536
     pub forecast_minutely_15: Option<u16>,
+    pub past_minutely_15: Option<u16>,
+    pub forecast_hours: Option<u32>,
+    pub past_hours: Option<u32>,
     pub start_date: Option<chrono::NaiveDate>,
     pub end_date: Option<chrono::NaiveDate>,
+    /// First hour of the hourly data, in the requested time zone
+    pub start_hour: Option<chrono::NaiveDateTime>,
+    /// Last hour of the hourly data (inclusive), in the requested time zone
+    pub end_hour: Option<chrono::NaiveDateTime>,
+    /// First step of the `minutely_15` data, in the requested time zone
+    pub start_minutely_15: Option<chrono::NaiveDateTime>,
+    /// Last step of the `minutely_15` data (inclusive), in the requested time zone
+    pub end_minutely_15: Option<chrono::NaiveDateTime>,
     pub models: Option<Vec<Model>>,
     pub cell_selection: Option<CellSelection>,
+    /// Panel tilt used for `global_tilted_irradiance`
+    pub tilt: Option<Tilt>,
+    /// Panel azimuth used for `global_tilted_irradiance`
+    pub azimuth: Option<Azimuth>,
     pub apikey: Option<String>,
 }
 
@@ -671,16 +428,310 @@ impl Default for Options {
             past_days: None,
             forecast_days: None,
             forecast_minutely_15: None,
+            past_minutely_15: None,
+            forecast_hours: None,
+            past_hours: None,
             start_date: None,
             end_date: None,
+            start_hour: None,
+            end_hour: None,
+            start_minutely_15: None,
+            end_minutely_15: None,
             models: None,
             cell_selection: None,
+            tilt: None,
+            azimuth: None,
             apikey: None,
         }
     }
 }
 
 impl Options {
+    /// Check the options against the forecast API constraints.
+    ///
+    /// Hour and `minutely_15` windows are checked against the current date, the same options may
+    /// become invalid as time passes. Their datetimes are local to `time_zone`, one extra day is
+    /// accepted on each side of the range to cover any time zone.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` describing the first invalid parameter.
+    pub fn validate(&self) -> Result<(), errors::ValidationError> {
+        self.validate_common()?;
+        validation::check_hours(("start_hour", self.start_hour), ("end_hour", self.end_hour))?;
+        validation::check_hours(
+            ("start_minutely_15", self.start_minutely_15),
+            ("end_minutely_15", self.end_minutely_15),
+        )?;
+
+        let has_dates = self.start_date.is_some();
+        let has_hours = self.start_hour.is_some();
+        let has_minutely_15 = self.start_minutely_15.is_some();
+        let days = [
+            ("past_days", self.past_days.is_some()),
+            ("forecast_days", self.forecast_days.is_some()),
+        ];
+        let hours = [
+            ("past_hours", self.past_hours.is_some()),
+            ("forecast_hours", self.forecast_hours.is_some()),
+        ];
+        let minutely_15 = [
+            ("past_minutely_15", self.past_minutely_15.is_some()),
+            ("forecast_minutely_15", self.forecast_minutely_15.is_some()),
+        ];
+
+        let dates = ("start_date", has_dates);
+        validation::check_exclusive(("start_hour", has_hours), dates)?;
+        validation::check_exclusive(("start_minutely_15", has_minutely_15), dates)?;
+        for range in days.iter().chain(&hours).chain(&minutely_15) {
+            validation::check_exclusive(*range, dates)?;
+        }
+        // Hour and minutely_15 windows replace the day based ranges of their section
+        for range in days.iter().chain(&hours) {
+            validation::check_exclusive(*range, ("start_hour", has_hours))?;
+        }
+        for range in days.iter().chain(&minutely_15) {
+            validation::check_exclusive(*range, ("start_minutely_15", has_minutely_15))?;
+        }
+
+        // Local dates differ from the UTC date by at most one day
+        let today = chrono::Utc::now().date_naive();
+        let first_date = today - chrono::Duration::days(i64::from(MAX_PAST_DAYS) + 1);
+        let last_date = today + chrono::Duration::days(i64::from(MAX_FORECAST_DAYS) + 1);
+        for (name, hour) in [
+            ("start_hour", self.start_hour),
+            ("end_hour", self.end_hour),
+            ("start_minutely_15", self.start_minutely_15),
+            ("end_minutely_15", self.end_minutely_15),
+        ] {
+            validation::check_optional_range(
+                name,
+                hour.map(|hour| hour.date()),
+                first_date,
+                last_date,
+            )?;
+        }
+
+        validation::check_optional_range("past_days", self.past_days, 0, MAX_PAST_DAYS)?;
+        validation::check_optional_range(
+            "forecast_days",
+            self.forecast_days,
+            0,
+            MAX_FORECAST_DAYS,
+        )?;
+        validation::check_optional_range(
+            "forecast_minutely_15",
+            self.forecast_minutely_15,
+            0,
+            MAX_FORECAST_MINUTELY_15,
+        )?;
+        validation::check_optional_range(
+            "past_minutely_15",
+            self.past_minutely_15,
+            0,
+            MAX_PAST_MINUTELY_15,
+        )?;
+        validation::check_optional_range(
+            "forecast_hours",
+            self.forecast_hours,
+            0,
+            u32::from(MAX_FORECAST_DAYS) * 24,
+        )?;
+        validation::check_optional_range(
+            "past_hours",
+            self.past_hours,
+            0,
+            u32::from(MAX_PAST_DAYS) * 24,
+        )?;
+
+        self.validate_models()
+    }
+
+    /// Check the options against the archive API constraints.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` describing the first invalid parameter.
+    pub fn validate_archive(&self) -> Result<(), errors::ValidationError> {
+        self.validate_common()?;
+
+        for (name, is_set) in [
+            ("past_days", self.past_days.is_some()),
+            ("forecast_days", self.forecast_days.is_some()),
+            ("forecast_minutely_15", self.forecast_minutely_15.is_some()),
+            ("past_minutely_15", self.past_minutely_15.is_some()),
+            ("forecast_hours", self.forecast_hours.is_some()),
+            ("past_hours", self.past_hours.is_some()),
+            ("start_hour", self.start_hour.is_some()),
+            ("start_minutely_15", self.start_minutely_15.is_some()),
+        ] {
+            validation::check_exclusive((name, is_set), ("archive", true))?;
+        }
+
+        for (section, is_set) in [
+            ("current", !self.current.is_empty()),
+            ("minutely_15", !self.minutely_15.is_empty()),
+        ] {
+            if is_set {
+                return Err(errors::ValidationError::UnsupportedSection {
+                    section: section.into(),
+                    by: "the archive API".into(),
+                });
+            }
+        }
+
+        let (Some(start_date), Some(end_date)) = (self.start_date, self.end_date) else {
+            return Err(errors::ValidationError::MissingParameter {
+                name: "archive".into(),
+                required: "start_date".into(),
+            });
+        };
+
+        let last_date =
+            chrono::Utc::now().date_naive() - chrono::Duration::days(ARCHIVE_DELAY_DAYS);
+        validation::check_range("start_date", start_date, ARCHIVE_FIRST_DATE, last_date)?;
+        validation::check_range("end_date", end_date, ARCHIVE_FIRST_DATE, last_date)?;
+
+        for model in self.models.iter().flatten() {
+            if !model.supports(models::Api::Archive) {
+                return Err(errors::ValidationError::UnsupportedModel {
+                    model: model.to_string(),
+                    api: "archive".into(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Models must be accepted by the forecast API and return every requested section
+    fn validate_models(&self) -> Result<(), errors::ValidationError> {
+        for model in self.models.iter().flatten() {
+            if !model.supports(models::Api::Forecast) {
+                return Err(errors::ValidationError::UnsupportedModel {
+                    model: model.to_string(),
+                    api: "forecast".into(),
+                });
+            }
+
+            for (section, is_set) in [
+                (models::Section::Current, !self.current.is_empty()),
+                (models::Section::Minutely15, !self.minutely_15.is_empty()),
+                (models::Section::Hourly, !self.hourly.is_empty()),
+                (models::Section::Daily, !self.daily.is_empty()),
+            ] {
+                if is_set && !model.supports_section(section) {
+                    return Err(errors::ValidationError::UnsupportedSection {
+                        section: section.to_string(),
+                        by: format!("model '{model}'"),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_common(&self) -> Result<(), errors::ValidationError> {
+        validation::check_location(&self.location)?;
+        validation::check_dates(self.start_date, self.end_date)
+    }
+
+    /// Parse options from an Open-Meteo URL or query string, such as the ones produced by the
+    /// website documentation builder. Parameters not supported by [`Options`] are ignored.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if a parameter is invalid or if the location is missing.
+    pub fn from_query_string(input: &str) -> Result<Self, errors::ConversionError> {
+        Ok(Self::from_query_string_with_unknown(input)?.0)
+    }
+
+    /// Same as [`Options::from_query_string`], also returning the parameters that were ignored
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if a parameter is invalid or if the location is missing.
+    pub fn from_query_string_with_unknown(
+        input: &str,
+    ) -> Result<(Self, Vec<(String, String)>), errors::ConversionError> {
+        let mut opts = Self::default();
+        let mut unknown = Vec::new();
+        let mut lat = None;
+        let mut lng = None;
+
+        for (k, v) in query::parse_pairs(input)? {
+            match k.as_str() {
+                "latitude" => lat = Some(query::parse_value(&k, &v)?),
+                "longitude" => lng = Some(query::parse_value(&k, &v)?),
+                "elevation" => opts.elevation = Some(v.as_str().try_into()?),
+                "minutely_15" => opts.minutely_15 = query::parse_list(&v),
+                "hourly" => opts.hourly = query::parse_list(&v),
+                "daily" => opts.daily = query::parse_list(&v),
+                "current" => opts.current = query::parse_list(&v),
+                "temperature_unit" => opts.temperature_unit = Some(v.try_into()?),
+                "wind_speed_unit" | "windspeed_unit" => opts.wind_speed_unit = Some(v.try_into()?),
+                "precipitation_unit" => opts.precipitation_unit = Some(v.try_into()?),
+                "timezone" => opts.time_zone = Some(v),
+                "past_days" => opts.past_days = Some(query::parse_value(&k, &v)?),
+                "forecast_days" => opts.forecast_days = Some(query::parse_value(&k, &v)?),
+                "forecast_minutely_15" => {
+                    opts.forecast_minutely_15 = Some(query::parse_value(&k, &v)?);
+                }
+                "past_minutely_15" => opts.past_minutely_15 = Some(query::parse_value(&k, &v)?),
+                "forecast_hours" => opts.forecast_hours = Some(query::parse_value(&k, &v)?),
+                "past_hours" => opts.past_hours = Some(query::parse_value(&k, &v)?),
+                "start_date" => opts.start_date = Some(query::parse_date(&k, &v)?),
+                "end_date" => opts.end_date = Some(query::parse_date(&k, &v)?),
+                "start_hour" => opts.start_hour = Some(query::parse_datetime(&k, &v)?),
+                "end_hour" => opts.end_hour = Some(query::parse_datetime(&k, &v)?),
+                "start_minutely_15" => {
+                    opts.start_minutely_15 = Some(query::parse_datetime(&k, &v)?);
+                }
+                "end_minutely_15" => {
+                    opts.end_minutely_15 = Some(query::parse_datetime(&k, &v)?);
+                }
+                "models" => {
+                    opts.models = Some(
+                        query::parse_list(&v)
+                            .into_iter()
+                            .map(Model::try_from)
+                            .collect::<Result<_, _>>()?,
+                    );
+                }
+                "cell_selection" => opts.cell_selection = Some(v.try_into()?),
+                "tilt" => opts.tilt = Some(query::parse_value::<f32>(&k, &v)?.try_into()?),
+                "azimuth" => opts.azimuth = Some(query::parse_value::<f32>(&k, &v)?.try_into()?),
+                "apikey" => opts.apikey = Some(v),
+                // Always requested as unixtime
+                "timeformat" => {}
+                _ => unknown.push((k, v)),
+            }
+        }
+
+        let (Some(lat), Some(lng)) = (lat, lng) else {
+            return Err(errors::ConversionError::MissingQueryParameter {
+                name: "latitude,longitude".into(),
+            });
+        };
+        opts.location = location::Location { lat, lng };
+
+        Ok((opts, unknown))
+    }
+
+    /// Build the request URL for the given endpoint, for example
+    /// `format!("{}forecast", client.forecast_endpoint)`.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the endpoint is not a valid URL.
+    pub fn to_url(&self, endpoint: &str) -> Result<reqwest::Url, Box<dyn Error>> {
+        Ok(reqwest::Url::parse_with_params(
+            endpoint,
+            self.clone().as_params(),
+        )?)
+    }
+
     #[must_use]
     pub fn as_params(self) -> Vec<(String, String)> {
         let mut params = Vec::new();
@@ -717,10 +768,22 @@ impl Options {
             params.push(("forecast_minutely_15".into(), v.to_string()));
         }
 
+        if let Some(v) = self.past_minutely_15 {
+            params.push(("past_minutely_15".into(), v.to_string()));
+        }
+
         if let Some(v) = self.forecast_days {
             params.push(("forecast_days".into(), v.to_string()));
         }
 
+        if let Some(v) = self.forecast_hours {
+            params.push(("forecast_hours".into(), v.to_string()));
+        }
+
+        if let Some(v) = self.past_hours {
+            params.push(("past_hours".into(), v.to_string()));
+        }
+
         if let Some(v) = self.start_date {
             params.push(("start_date".into(), v.format("%Y-%m-%d").to_string()));
         }
@@ -729,6 +792,17 @@ impl Options {
             params.push(("end_date".into(), v.format("%Y-%m-%d").to_string()));
         }
 
+        for (name, v) in [
+            ("start_hour", self.start_hour),
+            ("end_hour", self.end_hour),
+            ("start_minutely_15", self.start_minutely_15),
+            ("end_minutely_15", self.end_minutely_15),
+        ] {
+            if let Some(v) = v {
+                params.push((name.into(), v.format("%Y-%m-%dT%H:%M").to_string()));
+            }
+        }
+
         if !self.current.is_empty() {
             params.push(("current".into(), self.current.join(",")));
         }
@@ -762,6 +836,14 @@ impl Options {
             params.push(("cell_selection".into(), v.into()));
         }
 
+        if let Some(v) = self.tilt {
+            params.push(("tilt".into(), v.into()));
+        }
+
+        if let Some(v) = self.azimuth {
+            params.push(("azimuth".into(), v.into()));
+        }
+
         if let Some(apikey) = self.apikey {
             params.push(("apikey".into(), apikey.clone()));
         }
@@ -770,6 +852,194 @@ impl Options {
     }
 }
 
+/// Fluent builder for forecast [`Options`].
+///
+/// ```
+/// use open_meteo_rs::forecast::{ForecastRequest, Model, PrecipitationUnit, TemperatureUnit, WindSpeedUnit};
+///
+/// let opts = ForecastRequest::at(48.86, 2.35)
+///     .hourly(["temperature_2m", "precipitation"])
+///     .daily(["temperature_2m_max"])
+///     .units(TemperatureUnit::Celsius, WindSpeedUnit::Kmh, PrecipitationUnit::Millimeters)
+///     .forecast_days(3)
+///     .models([Model::BestMatch])
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ForecastRequest {
+    options: Options,
+}
+
+builder::request_builder!(ForecastRequest {
+    lists {
+        minutely_15: String,
+        hourly: String,
+        daily: String,
+        current: String,
+    }
+    values {
+        temperature_unit: TemperatureUnit,
+        wind_speed_unit: WindSpeedUnit,
+        precipitation_unit: PrecipitationUnit,
+        past_days: u8,
+        forecast_days: u8,
+        forecast_minutely_15: u16,
+        past_minutely_15: u16,
+        forecast_hours: u32,
+        past_hours: u32,
+    }
+});
+
+impl ForecastRequest {
+    #[must_use]
+    pub fn elevation(mut self, elevation: impl Into<Elevation>) -> Self {
+        self.options.elevation = Some(elevation.into());
+        self
+    }
+
+    #[must_use]
+    pub fn units(
+        mut self,
+        temperature: TemperatureUnit,
+        wind_speed: WindSpeedUnit,
+        precipitation: PrecipitationUnit,
+    ) -> Self {
+        self.options.temperature_unit = Some(temperature);
+        self.options.wind_speed_unit = Some(wind_speed);
+        self.options.precipitation_unit = Some(precipitation);
+        self
+    }
+
+    /// Request hourly data between two hours (inclusive)
+    #[must_use]
+    pub fn between_hours(
+        mut self,
+        start_hour: chrono::NaiveDateTime,
+        end_hour: chrono::NaiveDateTime,
+    ) -> Self {
+        self.options.start_hour = Some(start_hour);
+        self.options.end_hour = Some(end_hour);
+        self
+    }
+
+    /// Request `minutely_15` data between two steps (inclusive)
+    #[must_use]
+    pub fn between_minutely_15(
+        mut self,
+        start: chrono::NaiveDateTime,
+        end: chrono::NaiveDateTime,
+    ) -> Self {
+        self.options.start_minutely_15 = Some(start);
+        self.options.end_minutely_15 = Some(end);
+        self
+    }
+
+    #[must_use]
+    pub fn models(mut self, models: impl IntoIterator<Item = Model>) -> Self {
+        self.options
+            .models
+            .get_or_insert_with(Vec::new)
+            .extend(models);
+        self
+    }
+
+    /// Set the solar panel orientation used for `global_tilted_irradiance`
+    #[must_use]
+    pub fn panel(mut self, tilt: Tilt, azimuth: Azimuth) -> Self {
+        self.options.tilt = Some(tilt);
+        self.options.azimuth = Some(azimuth);
+        self
+    }
+
+    /// Validate and return the options.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the options are invalid or contradictory.
+    pub fn build(self) -> Result<Options, errors::ValidationError> {
+        self.options.validate()?;
+        Ok(self.options)
+    }
+}
+
+/// Fluent builder for archive [`Options`].
+///
+/// ```
+/// use open_meteo_rs::forecast::ArchiveRequest;
+///
+/// let start = chrono::NaiveDate::from_ymd_opt(2023, 5, 1).unwrap();
+/// let opts = ArchiveRequest::at(48.86, 2.35)
+///     .hourly(["temperature_2m"])
+///     .between(start, start + chrono::Duration::days(1))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct ArchiveRequest {
+    options: Options,
+}
+
+builder::request_builder!(ArchiveRequest {
+    lists {
+        hourly: String,
+        daily: String,
+    }
+    values {
+        temperature_unit: TemperatureUnit,
+        wind_speed_unit: WindSpeedUnit,
+        precipitation_unit: PrecipitationUnit,
+    }
+});
+
+impl ArchiveRequest {
+    #[must_use]
+    pub fn elevation(mut self, elevation: impl Into<Elevation>) -> Self {
+        self.options.elevation = Some(elevation.into());
+        self
+    }
+
+    #[must_use]
+    pub fn units(
+        mut self,
+        temperature: TemperatureUnit,
+        wind_speed: WindSpeedUnit,
+        precipitation: PrecipitationUnit,
+    ) -> Self {
+        self.options.temperature_unit = Some(temperature);
+        self.options.wind_speed_unit = Some(wind_speed);
+        self.options.precipitation_unit = Some(precipitation);
+        self
+    }
+
+    #[must_use]
+    pub fn models(mut self, models: impl IntoIterator<Item = Model>) -> Self {
+        self.options
+            .models
+            .get_or_insert_with(Vec::new)
+            .extend(models);
+        self
+    }
+
+    /// Set the solar panel orientation used for `global_tilted_irradiance`
+    #[must_use]
+    pub fn panel(mut self, tilt: Tilt, azimuth: Azimuth) -> Self {
+        self.options.tilt = Some(tilt);
+        self.options.azimuth = Some(azimuth);
+        self
+    }
+
+    /// Validate and return the options.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the options are invalid or if no date range was given.
+    pub fn build(self) -> Result<Options, errors::ValidationError> {
+        self.options.validate_archive()?;
+        Ok(self.options)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct ApiForecastResponse {
     pub latitude: Option<f64>,
@@ -789,29 +1059,70 @@ struct ApiForecastResponse {
     pub daily: Option<HashMap<String, serde_json::Value>>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ForecastResultItem {
     pub unit: Option<String>,
     pub value: serde_json::Value,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+impl ForecastResultItem {
+    /// Value as a number, `None` if the value is missing or not numeric
+    #[must_use]
+    pub fn as_f64(&self) -> Option<f64> {
+        self.value.as_f64()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ForecastResultHourly {
     pub datetime: chrono::NaiveDateTime,
     pub values: HashMap<String, ForecastResultItem>,
 }
 
+impl ForecastResultHourly {
+    /// Numeric value of the variable `name`
+    #[must_use]
+    pub fn value_f64(&self, name: &str) -> Option<f64> {
+        self.values.get(name).and_then(ForecastResultItem::as_f64)
+    }
+}
+
 pub type CurrentResult = ForecastResultHourly;
 pub type ForecastResultMinutely15 = ForecastResultHourly;
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ForecastResultDaily {
     pub date: chrono::NaiveDate,
     pub values: HashMap<String, ForecastResultItem>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+impl ForecastResultDaily {
+    /// Numeric value of the variable `name`
+    #[must_use]
+    pub fn value_f64(&self, name: &str) -> Option<f64> {
+        self.values.get(name).and_then(ForecastResultItem::as_f64)
+    }
+}
+
+/// Response metadata returned alongside the data, shared by forecast and air
+/// quality results
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Metadata {
+    /// Latitude of the grid cell the data was taken from
+    pub latitude: Option<f64>,
+    /// Longitude of the grid cell the data was taken from
+    pub longitude: Option<f64>,
+    pub elevation: Option<f32>,
+    pub generationtime_ms: Option<f64>,
+    /// Offset applied to every returned datetime
+    pub utc_offset_seconds: Option<i32>,
+    pub timezone: Option<String>,
+    pub timezone_abbreviation: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ForecastResult {
+    pub metadata: Metadata,
     pub current: Option<CurrentResult>,
     pub minutely_15: Option<Vec<ForecastResultMinutely15>>,
     pub hourly: Option<Vec<ForecastResultHourly>>,
@@ -823,8 +1134,9 @@ impl client::Client {
     ///
     /// ### Errors
     ///
-    /// Return an `Err` if api return an error or in case of network error.
+    /// Return an `Err` if options are invalid, if api return an error or in case of network error.
     pub async fn forecast(&self, opts: Options) -> Result<ForecastResult, Box<dyn Error>> {
+        opts.validate()?;
         self.request(opts, &format!("{}forecast", self.forecast_endpoint))
             .await
     }
@@ -833,13 +1145,46 @@ impl client::Client {
     ///
     /// ### Errors
     ///
-    /// Return an `Err` if api return an error or in case of network error.
+    /// Return an `Err` if options are invalid, if api return an error or in case of network error.
     pub async fn archive(&self, opts: Options) -> Result<ForecastResult, Box<dyn Error>> {
+        opts.validate_archive()?;
         self.request(opts, &format!("{}archive", self.archive_endpoint))
             .await
     }
 
-    #[allow(clippy::too_many_lines)]
+    /// Request forecast data for several locations at once, results are in the order of
+    /// `locations`. `opts.location` is ignored.
+    pub(crate) async fn forecast_many(
+        &self,
+        opts: Options,
+        locations: &[location::Location],
+    ) -> Result<Vec<ForecastResult>, Box<dyn Error>> {
+        opts.validate()?;
+        for location in locations {
+            validation::check_location(location)?;
+        }
+
+        let params = multi_location_params(opts, locations);
+        let url = reqwest::Url::parse_with_params(
+            &format!("{}forecast", self.forecast_endpoint),
+            params,
+        )?;
+        let res = self.http_client.get(url).send().await?;
+
+        if !res.status().is_success() {
+            return Err(Box::new(errors::ClientError::InvalidResponseStatus {
+                status_code: res.status().as_u16(),
+                text: res.text().await.unwrap_or(String::new()),
+            }));
+        }
+
+        let results = api_to_results(res.json().await?)?;
+        if results.len() != locations.len() {
+            return Err("cannot decode properly json input".into());
+        }
+        Ok(results)
+    }
+
     async fn request(
         &self,
         opts: Options,
@@ -849,183 +1194,231 @@ impl client::Client {
         let res = self.http_client.get(url).send().await?;
 
         if res.status().is_success() {
-            let api_res = res.json::<ApiForecastResponse>().await?;
-            let mut result = ForecastResult::default();
-
-            // Current weather
-            if let Some(current) = api_res.current {
-                let api_units = api_res.current_units.clone();
-                // Iterates on values
-                let mut current_result = CurrentResult::default();
-                for (k, v) in &current {
-                    if k == "time" {
-                        current_result.datetime = match v.as_i64() {
-                            Some(v) => unix_time_to_naive_datetime(v, 0),
-                            None => {
-                                return Err("cannot decode properly json input".into());
-                            }
-                        };
-                        continue;
-                    }
-                    // Try to find the unit
-                    let unit = api_units.as_ref().and_then(|units| units.get(k).cloned());
-                    let value = v.clone();
-                    current_result
-                        .values
-                        .insert(k.clone(), ForecastResultItem { unit, value });
-                }
+            return api_to_result(res.json::<ApiForecastResponse>().await?);
+        }
+
+        Err(Box::new(errors::ClientError::InvalidResponseStatus {
+            status_code: res.status().as_u16(),
+            text: res.text().await.unwrap_or(String::new()),
+        }))
+    }
+}
+
+/// Parameters of `opts` with comma separated coordinates, and elevation if set, for each of
+/// `locations`
+fn multi_location_params(opts: Options, locations: &[location::Location]) -> Vec<(String, String)> {
+    let join = |f: fn(&location::Location) -> f64| {
+        locations
+            .iter()
+            .map(|location| f(location).to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    };
+
+    opts.as_params()
+        .into_iter()
+        .map(|(k, v)| match k.as_str() {
+            "latitude" => (k, join(|location| location.lat)),
+            "longitude" => (k, join(|location| location.lng)),
+            "elevation" => (k, vec![v; locations.len()].join(",")),
+            _ => (k, v),
+        })
+        .collect()
+}
+
+/// Decode a multi-location response, a single location is returned as an object and several
+/// as an array
+fn api_to_results(value: serde_json::Value) -> Result<Vec<ForecastResult>, Box<dyn Error>> {
+    let api_res = match value {
+        serde_json::Value::Array(values) => values
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<Vec<ApiForecastResponse>, _>>()?,
+        value => vec![serde_json::from_value(value)?],
+    };
+    api_res.into_iter().map(api_to_result).collect()
+}
 
-                // Push current rec
-                result.current = Some(current_result);
+#[allow(clippy::too_many_lines)]
+fn api_to_result(api_res: ApiForecastResponse) -> Result<ForecastResult, Box<dyn Error>> {
+    let mut result = ForecastResult {
+        metadata: Metadata {
+            latitude: api_res.latitude,
+            longitude: api_res.longitude,
+            elevation: api_res.elevation,
+            generationtime_ms: api_res.generationtime_ms,
+            utc_offset_seconds: api_res.utc_offset_seconds,
+            timezone: api_res.timezone.clone(),
+            timezone_abbreviation: api_res.timezone_abbreviation.clone(),
+        },
+        ..Default::default()
+    };
+
+    // Current weather
+    if let Some(current) = api_res.current {
+        let api_units = api_res.current_units.clone();
+        // Iterates on values
+        let mut current_result = CurrentResult::default();
+        for (k, v) in &current {
+            if k == "time" {
+                current_result.datetime = match v.as_i64() {
+                    Some(v) => unix_time_to_naive_datetime(v, 0),
+                    None => {
+                        return Err("cannot decode properly json input".into());
+                    }
+                };
+                continue;
             }
+            // Try to find the unit
+            let unit = api_units.as_ref().and_then(|units| units.get(k).cloned());
+            let value = v.clone();
+            current_result
+                .values
+                .insert(k.clone(), ForecastResultItem { unit, value });
+        }
+
+        // Push current rec
+        result.current = Some(current_result);
+    }
+
+    // Get utc offset
+    let utc_offset_seconds = api_res.utc_offset_seconds.unwrap_or(0);
+
+    // Minutely 15
+    if let Some(minutely_15) = api_res.minutely_15 {
+        if let Some(minutely_15_date_times) = extract_times(&minutely_15, utc_offset_seconds)? {
+            if let Some(minutely_15_units) = api_res.minutely_15_units {
+                let mut minutely_15_result = Vec::new();
+
+                // Iterate on times
+                for (idx, time) in minutely_15_date_times.iter().enumerate() {
+                    let mut minutely_15_rec = ForecastResultMinutely15 {
+                        datetime: *time,
+                        ..Default::default()
+                    };
+
+                    // Iterates on values
+                    for (k, v) in &minutely_15 {
+                        if k == "time" {
+                            continue;
+                        }
+
+                        let mut item = ForecastResultItem::default();
+                        let Some(v_arr) = v.as_array() else {
+                            return Err("cannot decode properly json input".into());
+                        };
 
-            // Get utc offset
-            let utc_offset_seconds = api_res.utc_offset_seconds.unwrap_or(0);
-
-            // Minutely 15
-            if let Some(minutely_15) = api_res.minutely_15 {
-                if let Some(minutely_15_date_times) =
-                    extract_times(&minutely_15, utc_offset_seconds)?
-                {
-                    if let Some(minutely_15_units) = api_res.minutely_15_units {
-                        let mut minutely_15_result = Vec::new();
-
-                        // Iterate on times
-                        for (idx, time) in minutely_15_date_times.iter().enumerate() {
-                            let mut minutely_15_rec = ForecastResultMinutely15 {
-                                datetime: *time,
-                                ..Default::default()
-                            };
-
-                            // Iterates on values
-                            for (k, v) in &minutely_15 {
-                                if k == "time" {
-                                    continue;
-                                }
-
-                                let mut item = ForecastResultItem::default();
-                                let Some(v_arr) = v.as_array() else {
-                                    return Err("cannot decode properly json input".into());
-                                };
-
-                                let v_val = v_arr[idx].clone();
-                                item.value = v_val;
-
-                                // Try to find unit
-                                if let Some(unit) = minutely_15_units.get(k) {
-                                    item.unit = Some(unit.clone());
-                                }
-
-                                // Push to minutely_15 record
-                                minutely_15_rec.values.insert(k.clone(), item);
-                            }
-
-                            // Push minutely_15 rec
-                            minutely_15_result.push(minutely_15_rec);
+                        let v_val = v_arr[idx].clone();
+                        item.value = v_val;
+
+                        // Try to find unit
+                        if let Some(unit) = minutely_15_units.get(k) {
+                            item.unit = Some(unit.clone());
                         }
 
-                        result.minutely_15 = Some(minutely_15_result);
+                        // Push to minutely_15 record
+                        minutely_15_rec.values.insert(k.clone(), item);
                     }
+
+                    // Push minutely_15 rec
+                    minutely_15_result.push(minutely_15_rec);
                 }
+
+                result.minutely_15 = Some(minutely_15_result);
             }
+        }
+    }
 
-            // Hourly
-            if let Some(hourly) = api_res.hourly {
-                if let Some(hourly_date_times) = extract_times(&hourly, utc_offset_seconds)? {
-                    if let Some(hourly_units) = api_res.hourly_units {
-                        let mut hourly_result = Vec::new();
-
-                        // Iterate on times
-                        for (idx, time) in hourly_date_times.iter().enumerate() {
-                            let mut hourly_rec = forecast::ForecastResultHourly {
-                                datetime: *time,
-                                ..Default::default()
-                            };
-
-                            // Iterates on values
-                            for (k, v) in &hourly {
-                                if k == "time" {
-                                    continue;
-                                }
-
-                                let mut item = ForecastResultItem::default();
-                                let Some(v_arr) = v.as_array() else {
-                                    return Err("cannot decode properly json input".into());
-                                };
-
-                                let v_val = v_arr[idx].clone();
-                                item.value = v_val;
-
-                                // Try to find unit
-                                if let Some(unit) = hourly_units.get(k) {
-                                    item.unit = Some(unit.clone());
-                                }
-
-                                // Push to hourly record
-                                hourly_rec.values.insert(k.clone(), item);
-                            }
-
-                            // Push hourly rec
-                            hourly_result.push(hourly_rec);
+    // Hourly
+    if let Some(hourly) = api_res.hourly {
+        if let Some(hourly_date_times) = extract_times(&hourly, utc_offset_seconds)? {
+            if let Some(hourly_units) = api_res.hourly_units {
+                let mut hourly_result = Vec::new();
+
+                // Iterate on times
+                for (idx, time) in hourly_date_times.iter().enumerate() {
+                    let mut hourly_rec = forecast::ForecastResultHourly {
+                        datetime: *time,
+                        ..Default::default()
+                    };
+
+                    // Iterates on values
+                    for (k, v) in &hourly {
+                        if k == "time" {
+                            continue;
                         }
 
-                        result.hourly = Some(hourly_result);
+                        let mut item = ForecastResultItem::default();
+                        let Some(v_arr) = v.as_array() else {
+                            return Err("cannot decode properly json input".into());
+                        };
+
+                        let v_val = v_arr[idx].clone();
+                        item.value = v_val;
+
+                        // Try to find unit
+                        if let Some(unit) = hourly_units.get(k) {
+                            item.unit = Some(unit.clone());
+                        }
+
+                        // Push to hourly record
+                        hourly_rec.values.insert(k.clone(), item);
                     }
+
+                    // Push hourly rec
+                    hourly_result.push(hourly_rec);
                 }
+
+                result.hourly = Some(hourly_result);
             }
+        }
+    }
 
-            // Daily
-            if let Some(daily) = api_res.daily {
-                if let Some(daily_date_times) = extract_times(&daily, utc_offset_seconds)? {
-                    if let Some(daily_units) = api_res.daily_units {
-                        let mut daily_result = Vec::new();
-
-                        // Iterate on times
-                        for (idx, time) in daily_date_times.iter().enumerate() {
-                            let mut daily_rec = forecast::ForecastResultDaily {
-                                date: (*time).date(),
-                                ..Default::default()
-                            };
-
-                            // Iterates on values
-                            for (k, v) in &daily {
-                                if k == "time" {
-                                    continue;
-                                }
-
-                                let mut item = ForecastResultItem::default();
-                                let Some(v_arr) = v.as_array() else {
-                                    return Err("cannot decode properly json input".into());
-                                };
-                                let v_val = v_arr[idx].clone();
-                                item.value = v_val;
-
-                                // Try to find unit
-                                if let Some(unit) = daily_units.get(k) {
-                                    item.unit = Some(unit.clone());
-                                }
-
-                                // Push to daily record
-                                daily_rec.values.insert(k.clone(), item);
-                            }
-
-                            // Push daily rec
-                            daily_result.push(daily_rec);
+    // Daily
+    if let Some(daily) = api_res.daily {
+        if let Some(daily_date_times) = extract_times(&daily, utc_offset_seconds)? {
+            if let Some(daily_units) = api_res.daily_units {
+                let mut daily_result = Vec::new();
+
+                // Iterate on times
+                for (idx, time) in daily_date_times.iter().enumerate() {
+                    let mut daily_rec = forecast::ForecastResultDaily {
+                        date: (*time).date(),
+                        ..Default::default()
+                    };
+
+                    // Iterates on values
+                    for (k, v) in &daily {
+                        if k == "time" {
+                            continue;
                         }
 
-                        result.daily = Some(daily_result);
+                        let mut item = ForecastResultItem::default();
+                        let Some(v_arr) = v.as_array() else {
+                            return Err("cannot decode properly json input".into());
+                        };
+                        let v_val = v_arr[idx].clone();
+                        item.value = v_val;
+
+                        // Try to find unit
+                        if let Some(unit) = daily_units.get(k) {
+                            item.unit = Some(unit.clone());
+                        }
+
+                        // Push to daily record
+                        daily_rec.values.insert(k.clone(), item);
                     }
+
+                    // Push daily rec
+                    daily_result.push(daily_rec);
                 }
-            }
 
-            return Ok(result);
+                result.daily = Some(daily_result);
+            }
         }
-
-        Err(Box::new(errors::ClientError::InvalidResponseStatus {
-            status_code: res.status().as_u16(),
-            text: res.text().await.unwrap_or(String::new()),
-        }))
     }
+
+    Ok(result)
 }
 
 #[must_use]
@@ -1105,6 +1498,305 @@ mod tests {
         println!("{res:#?}");
     }
 
+    #[test]
+    fn validate_model_sections() {
+        let opts = Options {
+            models: Some(vec![Model::IconEu]),
+            hourly: vec!["temperature_2m".into()],
+            current: vec!["temperature_2m".into()],
+            ..Default::default()
+        };
+        assert!(opts.validate().is_ok());
+
+        let opts = Options {
+            minutely_15: vec!["temperature_2m".into()],
+            ..opts
+        };
+        let err = opts.validate().unwrap_err();
+        assert!(matches!(
+            err,
+            errors::ValidationError::UnsupportedSection { ref section, .. } if section == "minutely_15"
+        ));
+        assert!(err.to_string().contains("icon_eu"));
+
+        for model in [Model::IconD2, Model::BestMatch] {
+            let opts = Options {
+                models: Some(vec![model]),
+                ..opts.clone()
+            };
+            assert!(opts.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_options() {
+        let opts = Options {
+            past_days: Some(2),
+            start_date: Some(chrono::Utc::now().date_naive()),
+            end_date: Some(chrono::Utc::now().date_naive()),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.validate(),
+            Err(errors::ValidationError::MutuallyExclusive { .. })
+        ));
+
+        let opts = Options {
+            forecast_days: Some(17),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.validate(),
+            Err(errors::ValidationError::OutOfRange { .. })
+        ));
+        assert!(Options::default().validate().is_ok());
+    }
+
+    #[test]
+    fn validate_forecast_models() {
+        let opts = Options {
+            models: Some(vec![Model::Era5]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.validate(),
+            Err(errors::ValidationError::UnsupportedModel { .. })
+        ));
+
+        // "auto" is refused when parsed and when set by hand
+        assert!(Options::from_query_string("latitude=1&longitude=2&models=auto").is_err());
+        let opts = Options {
+            models: Some(vec![Model::Other("auto".into())]),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.validate(),
+            Err(errors::ValidationError::UnsupportedModel { .. })
+        ));
+    }
+
+    #[test]
+    fn validate_hour_windows() {
+        let now = chrono::Utc::now().naive_utc();
+        let opts = Options {
+            start_hour: Some(now),
+            end_hour: Some(now + Duration::hours(6)),
+            forecast_minutely_15: Some(8),
+            ..Default::default()
+        };
+        assert!(opts.validate().is_ok());
+
+        for opts in [
+            Options {
+                forecast_hours: Some(6),
+                ..opts.clone()
+            },
+            Options {
+                start_minutely_15: Some(now),
+                end_minutely_15: Some(now),
+                ..opts.clone()
+            },
+            Options {
+                end_hour: Some(now - Duration::hours(1)),
+                ..opts.clone()
+            },
+            Options {
+                end_hour: None,
+                ..opts.clone()
+            },
+            Options {
+                forecast_hours: Some(16 * 24 + 1),
+                ..Default::default()
+            },
+        ] {
+            assert!(opts.validate().is_err());
+        }
+        assert!(opts.validate_archive().is_err());
+
+        // The error names the invalid pair
+        let err = Options {
+            start_minutely_15: Some(now),
+            end_minutely_15: Some(now - Duration::hours(1)),
+            ..Default::default()
+        }
+        .validate()
+        .unwrap_err();
+        assert!(err.to_string().starts_with("'start_minutely_15'"));
+
+        // Windows are limited to the past and forecast days of the API, give or take a day for
+        // the time zone
+        for (start, end) in [
+            (now - Duration::days(93), now),
+            (now, now + Duration::days(17)),
+        ] {
+            let opts = Options {
+                start_hour: Some(start),
+                end_hour: Some(end),
+                time_zone: Some("Pacific/Kiritimati".into()),
+                ..Default::default()
+            };
+            assert!(opts.validate().is_ok());
+        }
+        for (start, end) in [
+            (now - Duration::days(94), now),
+            (now, now + Duration::days(18)),
+        ] {
+            let opts = Options {
+                start_minutely_15: Some(start),
+                end_minutely_15: Some(end),
+                ..Default::default()
+            };
+            assert!(matches!(
+                opts.validate(),
+                Err(errors::ValidationError::OutOfRange { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn validate_archive_options() {
+        let start_date = chrono::NaiveDate::from_ymd_opt(2023, 5, 2).unwrap();
+        let mut opts = Options {
+            start_date: Some(start_date),
+            end_date: Some(start_date - Duration::days(1)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            opts.validate_archive(),
+            Err(errors::ValidationError::InvalidDateRange { .. })
+        ));
+
+        opts.end_date = Some(start_date);
+        assert!(opts.validate_archive().is_ok());
+
+        opts.end_date = Some(chrono::Utc::now().date_naive());
+        assert!(opts.validate_archive().is_err());
+    }
+
+    #[test]
+    fn build_request() {
+        let opts = ForecastRequest::at(48.86, 2.35)
+            .hourly(["temperature_2m"])
+            .hourly(vec![String::from("precipitation")])
+            .models([Model::IconSeamless, Model::GfsSeamless])
+            .build()
+            .unwrap();
+        assert_eq!(opts.hourly, vec!["temperature_2m", "precipitation"]);
+        assert_eq!(opts.models.map(|m| m.len()), Some(2));
+
+        let today = chrono::Utc::now().date_naive();
+        assert!(ForecastRequest::at(48.86, 2.35)
+            .past_days(1)
+            .between(today, today)
+            .build()
+            .is_err());
+        assert!(ArchiveRequest::at(48.86, 2.35).build().is_err());
+    }
+
+    #[test]
+    fn multi_location_request() {
+        let opts = Options {
+            elevation: Some(Elevation::Value(100.0)),
+            cell_selection: Some(CellSelection::Land),
+            ..Default::default()
+        };
+        let locations = [
+            location::Location {
+                lat: 52.52,
+                lng: 13.41,
+            },
+            location::Location {
+                lat: 48.85,
+                lng: 2.35,
+            },
+        ];
+        let params: HashMap<_, _> = multi_location_params(opts, &locations)
+            .into_iter()
+            .collect();
+        assert_eq!(params["latitude"], "52.52,48.85");
+        assert_eq!(params["longitude"], "13.41,2.35");
+        assert_eq!(params["elevation"], "100,100");
+        assert_eq!(params["cell_selection"], "land");
+
+        let response = serde_json::json!([
+            {"latitude": 52.52, "longitude": 13.42, "utc_offset_seconds": 0,
+             "hourly_units": {"time": "unixtime", "temperature_2m": "°C"},
+             "hourly": {"time": [0], "temperature_2m": [12.5]}},
+            {"latitude": 48.84, "longitude": 2.34, "utc_offset_seconds": 0,
+             "hourly_units": {"time": "unixtime", "temperature_2m": "°C"},
+             "hourly": {"time": [0], "temperature_2m": [15.0]}},
+        ]);
+        let results = api_to_results(response).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[1].metadata.latitude, Some(48.84));
+        assert_eq!(
+            results[1].hourly.as_ref().unwrap()[0].value_f64("temperature_2m"),
+            Some(15.0)
+        );
+    }
+
+    #[test]
+    fn query_string_round_trip() {
+        let opts = ForecastRequest::at(48.86, 2.35)
+            .elevation(35.5)
+            .hourly(["temperature_2m", "global_tilted_irradiance"])
+            .daily(["temperature_2m_max"])
+            .units(
+                TemperatureUnit::Fahrenheit,
+                WindSpeedUnit::Kn,
+                PrecipitationUnit::Inches,
+            )
+            .time_zone("Europe/Paris")
+            .forecast_days(3)
+            .models([Model::IconSeamless, Model::MeteofranceAromeFranceHd])
+            .cell_selection(CellSelection::Sea)
+            .panel(30.0.try_into().unwrap(), (-45.0).try_into().unwrap())
+            .build()
+            .unwrap();
+
+        let url = opts
+            .to_url("https://api.open-meteo.com/v1/forecast")
+            .unwrap();
+        assert_eq!(Options::from_query_string(url.as_str()).unwrap(), opts);
+
+        let parsed = Options::from_query_string(
+            "latitude=52.52&longitude=13.41&hourly=temperature_2m,wind_speed_10m&models=best_match",
+        )
+        .unwrap();
+        assert_eq!(parsed.hourly.len(), 2);
+        assert_eq!(parsed.models, Some(vec![Model::BestMatch]));
+        assert!(Options::from_query_string("latitude=52.52&foo=bar").is_err());
+
+        // Parameters of the documentation builder that are not modelled are ignored
+        let (parsed, unknown) = Options::from_query_string_with_unknown(
+            "latitude=52.52&longitude=13.41&temporal_resolution=hourly_3&bounding_box=1,2,3,4",
+        )
+        .unwrap();
+        assert_eq!(unknown.len(), 2);
+        assert_eq!(unknown[0].0, "temporal_resolution");
+        assert_eq!(parsed.time_zone, Options::default().time_zone);
+        assert_eq!(
+            Options::from_query_string("latitude=52.52&longitude=13.41&bounding_box=1,2,3,4")
+                .unwrap(),
+            parsed
+        );
+
+        let parsed = Options::from_query_string(
+            "latitude=52.52&longitude=13.41&minutely_15=precipitation&forecast_hours=6\
+             &start_minutely_15=2024-06-01T12:00&end_minutely_15=2024-06-01T18:45",
+        )
+        .unwrap();
+        assert_eq!(parsed.forecast_hours, Some(6));
+        let url = parsed
+            .to_url("https://api.open-meteo.com/v1/forecast")
+            .unwrap();
+        assert!(url.as_str().contains("end_minutely_15=2024-06-01T18%3A45"));
+        assert_eq!(Options::from_query_string(url.as_str()).unwrap(), parsed);
+
+        let json = serde_json::to_string(&opts).unwrap();
+        assert_eq!(serde_json::from_str::<Options>(&json).unwrap(), opts);
+    }
+
     #[tokio::test]
     async fn get_forecast_parallel() {
         let clt = client::Client::new();
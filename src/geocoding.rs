@@ -1,12 +1,34 @@
-use super::{client, errors};
+use super::{client, errors, forecast, location};
 use serde::{Deserialize, Serialize};
 use std::error::Error;
+use std::fmt::Display;
+
+/// Response format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Json,
+    Protobuf,
+}
+
+impl Display for Format {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json => write!(f, "json"),
+            Self::Protobuf => write!(f, "protobuf"),
+        }
+    }
+}
 
 #[derive(Debug, Clone, Default)]
 pub struct Options {
     pub name: Option<String>,
     pub language: Option<String>,
     pub count: Option<u16>,
+    /// ISO-3166-1 alpha2 country code
+    pub country_code: Option<String>,
+    /// Only used by [`Client::geocoding_raw`](client::Client::geocoding_raw), other requests
+    /// always use json
+    pub format: Option<Format>,
     pub apikey: Option<String>,
 }
 
@@ -29,6 +51,24 @@ impl Options {
         self
     }
 
+    #[must_use]
+    pub fn with_country_code(mut self, country_code: String) -> Self {
+        self.country_code = Some(country_code);
+        self
+    }
+
+    #[must_use]
+    pub fn with_format(mut self, format: Format) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    #[must_use]
+    pub fn with_apikey(mut self, apikey: String) -> Self {
+        self.apikey = Some(apikey);
+        self
+    }
+
     fn into_params(self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -44,6 +84,14 @@ impl Options {
             params.push(("count".into(), v.to_string()));
         }
 
+        if let Some(v) = self.country_code {
+            params.push(("countryCode".into(), v));
+        }
+
+        if let Some(v) = self.format {
+            params.push(("format".into(), v.to_string()));
+        }
+
         if let Some(apikey) = self.apikey {
             params.push(("apikey".into(), apikey.clone()));
         }
@@ -52,20 +100,317 @@ impl Options {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// Class of a `GeoNames` feature
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeatureClass {
+    /// `A`, country, state, region
+    AdministrativeBoundary,
+    /// `H`, stream, lake
+    Hydrographic,
+    /// `L`, park, area
+    Area,
+    /// `P`, city, village
+    PopulatedPlace,
+    /// `R`, road, railroad
+    Road,
+    /// `S`, spot, building, farm
+    Spot,
+    /// `T`, mountain, hill, rock
+    Terrain,
+    /// `U`
+    Undersea,
+    /// `V`, forest, heath
+    Vegetation,
+}
+
+impl FeatureClass {
+    #[must_use]
+    pub fn from_letter(letter: char) -> Option<Self> {
+        match letter {
+            'A' => Some(Self::AdministrativeBoundary),
+            'H' => Some(Self::Hydrographic),
+            'L' => Some(Self::Area),
+            'P' => Some(Self::PopulatedPlace),
+            'R' => Some(Self::Road),
+            'S' => Some(Self::Spot),
+            'T' => Some(Self::Terrain),
+            'U' => Some(Self::Undersea),
+            'V' => Some(Self::Vegetation),
+            _ => None,
+        }
+    }
+}
+
+/// `GeoNames` feature code, see <https://www.geonames.org/export/codes.html>
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[non_exhaustive]
+pub enum FeatureCode {
+    /// first-order administrative division
+    Adm1,
+    Adm2,
+    Adm3,
+    Adm4,
+    Adm5,
+    Admd,
+    /// independent political entity
+    Pcli,
+    Pcld,
+    Pcls,
+    Pclix,
+    Terr,
+    /// populated place
+    Ppl,
+    /// seat of a first-order administrative division
+    Ppla,
+    Ppla2,
+    Ppla3,
+    Ppla4,
+    Ppla5,
+    /// capital of a political entity
+    Pplc,
+    Pplch,
+    Pplf,
+    Pplg,
+    Pplh,
+    Ppll,
+    Pplq,
+    Pplr,
+    Ppls,
+    Pplw,
+    /// section of populated place
+    Pplx,
+    Stlmt,
+    /// lake
+    Lk,
+    /// stream
+    Stm,
+    Bay,
+    /// park
+    Prk,
+    /// region
+    Rgn,
+    /// airport
+    Airp,
+    /// meteorological station
+    Stnm,
+    /// island
+    Isl,
+    /// mountain
+    Mt,
+    Pass,
+    /// volcano
+    Vlc,
+    Other(String),
+}
+
+impl FeatureCode {
+    /// Rank used to pick the best match of a search, higher is better
+    #[must_use]
+    pub fn importance(&self) -> u8 {
+        match self {
+            Self::Pplc => 6,
+            Self::Ppla => 5,
+            Self::Ppla2 | Self::Pplg => 4,
+            Self::Ppla3 | Self::Ppla4 | Self::Ppla5 => 3,
+            _ if self.class() == Some(FeatureClass::PopulatedPlace) => 2,
+            Self::Other(_) => 0,
+            _ => 1,
+        }
+    }
+
+    /// Class of the feature, `None` for unknown codes
+    #[must_use]
+    pub fn class(&self) -> Option<FeatureClass> {
+        match self {
+            Self::Adm1
+            | Self::Adm2
+            | Self::Adm3
+            | Self::Adm4
+            | Self::Adm5
+            | Self::Admd
+            | Self::Pcli
+            | Self::Pcld
+            | Self::Pcls
+            | Self::Pclix
+            | Self::Terr => Some(FeatureClass::AdministrativeBoundary),
+            Self::Ppl
+            | Self::Ppla
+            | Self::Ppla2
+            | Self::Ppla3
+            | Self::Ppla4
+            | Self::Ppla5
+            | Self::Pplc
+            | Self::Pplch
+            | Self::Pplf
+            | Self::Pplg
+            | Self::Pplh
+            | Self::Ppll
+            | Self::Pplq
+            | Self::Pplr
+            | Self::Ppls
+            | Self::Pplw
+            | Self::Pplx
+            | Self::Stlmt => Some(FeatureClass::PopulatedPlace),
+            Self::Lk | Self::Stm | Self::Bay => Some(FeatureClass::Hydrographic),
+            Self::Prk | Self::Rgn => Some(FeatureClass::Area),
+            Self::Airp | Self::Stnm => Some(FeatureClass::Spot),
+            Self::Isl | Self::Mt | Self::Pass | Self::Vlc => Some(FeatureClass::Terrain),
+            Self::Other(_) => None,
+        }
+    }
+}
+
+impl Display for FeatureCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Adm1 => write!(f, "ADM1"),
+            Self::Adm2 => write!(f, "ADM2"),
+            Self::Adm3 => write!(f, "ADM3"),
+            Self::Adm4 => write!(f, "ADM4"),
+            Self::Adm5 => write!(f, "ADM5"),
+            Self::Admd => write!(f, "ADMD"),
+            Self::Pcli => write!(f, "PCLI"),
+            Self::Pcld => write!(f, "PCLD"),
+            Self::Pcls => write!(f, "PCLS"),
+            Self::Pclix => write!(f, "PCLIX"),
+            Self::Terr => write!(f, "TERR"),
+            Self::Ppl => write!(f, "PPL"),
+            Self::Ppla => write!(f, "PPLA"),
+            Self::Ppla2 => write!(f, "PPLA2"),
+            Self::Ppla3 => write!(f, "PPLA3"),
+            Self::Ppla4 => write!(f, "PPLA4"),
+            Self::Ppla5 => write!(f, "PPLA5"),
+            Self::Pplc => write!(f, "PPLC"),
+            Self::Pplch => write!(f, "PPLCH"),
+            Self::Pplf => write!(f, "PPLF"),
+            Self::Pplg => write!(f, "PPLG"),
+            Self::Pplh => write!(f, "PPLH"),
+            Self::Ppll => write!(f, "PPLL"),
+            Self::Pplq => write!(f, "PPLQ"),
+            Self::Pplr => write!(f, "PPLR"),
+            Self::Ppls => write!(f, "PPLS"),
+            Self::Pplw => write!(f, "PPLW"),
+            Self::Pplx => write!(f, "PPLX"),
+            Self::Stlmt => write!(f, "STLMT"),
+            Self::Lk => write!(f, "LK"),
+            Self::Stm => write!(f, "STM"),
+            Self::Bay => write!(f, "BAY"),
+            Self::Prk => write!(f, "PRK"),
+            Self::Rgn => write!(f, "RGN"),
+            Self::Airp => write!(f, "AIRP"),
+            Self::Stnm => write!(f, "STNM"),
+            Self::Isl => write!(f, "ISL"),
+            Self::Mt => write!(f, "MT"),
+            Self::Pass => write!(f, "PASS"),
+            Self::Vlc => write!(f, "VLC"),
+            Self::Other(code) => write!(f, "{code}"),
+        }
+    }
+}
+
+impl From<FeatureCode> for String {
+    fn from(value: FeatureCode) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for FeatureCode {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "ADM1" => Ok(Self::Adm1),
+            "ADM2" => Ok(Self::Adm2),
+            "ADM3" => Ok(Self::Adm3),
+            "ADM4" => Ok(Self::Adm4),
+            "ADM5" => Ok(Self::Adm5),
+            "ADMD" => Ok(Self::Admd),
+            "PCLI" => Ok(Self::Pcli),
+            "PCLD" => Ok(Self::Pcld),
+            "PCLS" => Ok(Self::Pcls),
+            "PCLIX" => Ok(Self::Pclix),
+            "TERR" => Ok(Self::Terr),
+            "PPL" => Ok(Self::Ppl),
+            "PPLA" => Ok(Self::Ppla),
+            "PPLA2" => Ok(Self::Ppla2),
+            "PPLA3" => Ok(Self::Ppla3),
+            "PPLA4" => Ok(Self::Ppla4),
+            "PPLA5" => Ok(Self::Ppla5),
+            "PPLC" => Ok(Self::Pplc),
+            "PPLCH" => Ok(Self::Pplch),
+            "PPLF" => Ok(Self::Pplf),
+            "PPLG" => Ok(Self::Pplg),
+            "PPLH" => Ok(Self::Pplh),
+            "PPLL" => Ok(Self::Ppll),
+            "PPLQ" => Ok(Self::Pplq),
+            "PPLR" => Ok(Self::Pplr),
+            "PPLS" => Ok(Self::Ppls),
+            "PPLW" => Ok(Self::Pplw),
+            "PPLX" => Ok(Self::Pplx),
+            "STLMT" => Ok(Self::Stlmt),
+            "LK" => Ok(Self::Lk),
+            "STM" => Ok(Self::Stm),
+            "BAY" => Ok(Self::Bay),
+            "PRK" => Ok(Self::Prk),
+            "RGN" => Ok(Self::Rgn),
+            "AIRP" => Ok(Self::Airp),
+            "STNM" => Ok(Self::Stnm),
+            "ISL" => Ok(Self::Isl),
+            "MT" => Ok(Self::Mt),
+            "PASS" => Ok(Self::Pass),
+            "VLC" => Ok(Self::Vlc),
+            "" => Err(errors::ConversionError::InvalidFeatureCode {
+                code: value.to_string(),
+            }),
+            _ => Ok(Self::Other(value.to_string())),
+        }
+    }
+}
+
+impl TryFrom<String> for FeatureCode {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+/// Empty feature codes are returned for some places, map them to `None` instead of failing
+/// the whole response
+fn deserialize_feature_code<'de, D: serde::Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<FeatureCode>, D::Error> {
+    let code: Option<String> = Option::deserialize(deserializer)?;
+    Ok(code.and_then(|code| FeatureCode::try_from(code).ok()))
+}
+
+/// Filter applied locally on geocoding results
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    /// ISO-3166-1 alpha2 country codes, any country if empty
+    pub country_codes: Vec<String>,
+    pub min_population: Option<i64>,
+    /// Any feature class if empty
+    pub feature_classes: Vec<FeatureClass>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GeocodingResponse {
     pub results: Option<Vec<GeocodingResult>>,
     pub generationtime_ms: Option<f64>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct GeocodingResult {
     pub id: Option<i64>,
     pub name: Option<String>,
     pub latitude: Option<f64>,
     pub longitude: Option<f64>,
     pub elevation: Option<f64>,
-    pub feature_code: Option<String>,
+    /// `None` if the API returns an empty code
+    #[serde(default, deserialize_with = "deserialize_feature_code")]
+    pub feature_code: Option<FeatureCode>,
     pub country_code: Option<String>,
     pub admin1_id: Option<i64>,
     pub admin3_id: Option<i64>,
@@ -82,6 +427,123 @@ pub struct GeocodingResult {
     pub admin2: Option<String>,
 }
 
+impl Filter {
+    #[must_use]
+    pub fn with_country_code(mut self, country_code: String) -> Self {
+        self.country_codes.push(country_code);
+        self
+    }
+
+    #[must_use]
+    pub fn with_min_population(mut self, min_population: i64) -> Self {
+        self.min_population = Some(min_population);
+        self
+    }
+
+    #[must_use]
+    pub fn with_feature_class(mut self, feature_class: FeatureClass) -> Self {
+        self.feature_classes.push(feature_class);
+        self
+    }
+
+    /// Whether `result` passes the filter, results missing a filtered field are rejected
+    #[must_use]
+    pub fn matches(&self, result: &GeocodingResult) -> bool {
+        let country_ok = self.country_codes.is_empty()
+            || result.country_code.as_ref().is_some_and(|code| {
+                self.country_codes
+                    .iter()
+                    .any(|c| c.eq_ignore_ascii_case(code))
+            });
+        let population_ok = self
+            .min_population
+            .is_none_or(|min| result.population.is_some_and(|p| p >= min));
+        let class_ok = self.feature_classes.is_empty()
+            || result
+                .feature_code
+                .as_ref()
+                .and_then(FeatureCode::class)
+                .is_some_and(|class| self.feature_classes.contains(&class));
+
+        country_ok && population_ok && class_ok
+    }
+}
+
+impl GeocodingResponse {
+    /// Results passing `filter`, in the order returned by the API
+    #[must_use]
+    pub fn filter(self, filter: &Filter) -> Vec<GeocodingResult> {
+        self.results
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|result| filter.matches(result))
+            .collect()
+    }
+}
+
+impl GeocodingResult {
+    /// Coordinates of the result, `None` if the API did not return them
+    #[must_use]
+    pub fn location(&self) -> Option<location::Location> {
+        Some(location::Location {
+            lat: self.latitude?,
+            lng: self.longitude?,
+        })
+    }
+
+    /// Time zone of the result, `None` if missing or unknown
+    #[must_use]
+    pub fn timezone(&self) -> Option<chrono_tz::Tz> {
+        self.timezone.as_deref()?.parse().ok()
+    }
+
+    /// Forecast options for the result location, with its elevation and time zone
+    #[must_use]
+    pub fn forecast_options(&self) -> Option<forecast::Options> {
+        self.apply_to(forecast::Options::default())
+    }
+
+    /// Set the location, elevation and time zone of `opts` from the result, `None` if the
+    /// result has no coordinates. The elevation of `opts` is kept if the result has none.
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn apply_to(&self, opts: forecast::Options) -> Option<forecast::Options> {
+        Some(forecast::Options {
+            location: self.location()?,
+            elevation: self
+                .elevation
+                .map(|e| forecast::Elevation::from(e as f32))
+                .or(opts.elevation),
+            time_zone: self.timezone.clone().or(Some("auto".into())),
+            ..opts
+        })
+    }
+}
+
+/// Sort results best match first: capitals, then administrative seats, then other populated
+/// places, then by decreasing population. The API order is kept on ties.
+pub fn sort_by_relevance(results: &mut [GeocodingResult]) {
+    results.sort_by_key(|result| {
+        (
+            std::cmp::Reverse(
+                result
+                    .feature_code
+                    .as_ref()
+                    .map_or(0, FeatureCode::importance),
+            ),
+            std::cmp::Reverse(result.population.unwrap_or(0)),
+        )
+    });
+}
+
+/// Place resolved by [`Client::forecast_for_place`](client::Client::forecast_for_place) and
+/// its forecast
+#[derive(Debug, Clone)]
+pub struct PlaceForecast {
+    pub place: GeocodingResult,
+    pub forecast: forecast::ForecastResult,
+}
+
 impl client::Client {
     /// Make a geocoding request.
     ///
@@ -89,11 +551,116 @@ impl client::Client {
     ///
     /// Will return `Err` if api return an invaid response or in case of network error.
     pub async fn geocoding(&self, opts: Options) -> Result<GeocodingResponse, Box<dyn Error>> {
-        let url = reqwest::Url::parse_with_params(&self.geocoding_endpoint, opts.into_params())?;
+        let opts = opts.with_format(Format::Json);
+        let res = self
+            .geocoding_request(&self.geocoding_endpoint, opts.into_params())
+            .await?;
+        Ok(res.json().await?)
+    }
+
+    /// Make a geocoding request and return the response body as is, in the requested `format`.
+    ///
+    /// ### Errors
+    ///
+    /// Will return `Err` if api return an invaid response or in case of network error.
+    pub async fn geocoding_raw(&self, opts: Options) -> Result<Vec<u8>, Box<dyn Error>> {
+        let res = self
+            .geocoding_request(&self.geocoding_endpoint, opts.into_params())
+            .await?;
+        Ok(res.bytes().await?.to_vec())
+    }
+
+    /// Get a location by its `GeoNames` id, `name` and `count` options are ignored.
+    ///
+    /// ### Errors
+    ///
+    /// Will return `Err` if api return an invaid response or in case of network error.
+    pub async fn geocoding_by_id(
+        &self,
+        id: i64,
+        opts: Options,
+    ) -> Result<GeocodingResult, Box<dyn Error>> {
+        let opts = Options {
+            name: None,
+            count: None,
+            ..opts.with_format(Format::Json)
+        };
+        let mut params = opts.into_params();
+        params.push(("id".into(), id.to_string()));
+
+        let res = self
+            .geocoding_request(&self.geocoding_get_endpoint, params)
+            .await?;
+        Ok(res.json().await?)
+    }
+
+    /// Geocode `name`, pick the best match and request its forecast. The location, elevation
+    /// and time zone of `opts` are set from the place.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if no place matches, if options are invalid, if api return an error or in
+    /// case of network error.
+    pub async fn forecast_for_place(
+        &self,
+        name: &str,
+        opts: forecast::Options,
+    ) -> Result<PlaceForecast, Box<dyn Error>> {
+        self.forecast_for_place_with(name, opts, |_| Some(0)).await
+    }
+
+    /// Same as [`Client::forecast_for_place`](client::Client::forecast_for_place), `pick` is
+    /// given the candidates best match first and returns the index of the place to use, or
+    /// `None` to give up.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if no place is picked, if options are invalid, if api return an error or
+    /// in case of network error.
+    pub async fn forecast_for_place_with(
+        &self,
+        name: &str,
+        opts: forecast::Options,
+        pick: impl FnOnce(&[GeocodingResult]) -> Option<usize>,
+    ) -> Result<PlaceForecast, Box<dyn Error>> {
+        // `opts.apikey` is a forecast API key, the search is sent without it
+        let search = Options::default().with_name(name.to_string());
+
+        let mut candidates: Vec<GeocodingResult> = self
+            .geocoding(search)
+            .await?
+            .results
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|result| result.location().is_some())
+            .collect();
+        sort_by_relevance(&mut candidates);
+
+        let not_found = || errors::ClientError::PlaceNotFound {
+            name: name.to_string(),
+        };
+        let idx = pick(&candidates).ok_or_else(not_found)?;
+        if idx >= candidates.len() {
+            return Err(Box::new(not_found()));
+        }
+        let place = candidates.swap_remove(idx);
+        let opts = place.apply_to(opts).ok_or_else(not_found)?;
+
+        Ok(PlaceForecast {
+            forecast: self.forecast(opts).await?,
+            place,
+        })
+    }
+
+    async fn geocoding_request(
+        &self,
+        endpoint: &str,
+        params: Vec<(String, String)>,
+    ) -> Result<reqwest::Response, Box<dyn Error>> {
+        let url = reqwest::Url::parse_with_params(endpoint, params)?;
         let res = self.http_client.get(url).send().await?;
 
         if res.status().is_success() {
-            let res = res.json().await?;
             return Ok(res);
         }
 
@@ -118,4 +685,129 @@ mod tests {
 
         assert!(!res.results.unwrap().is_empty());
     }
+
+    #[test]
+    fn pick_best_match() {
+        let mut results = vec![
+            GeocodingResult {
+                id: Some(1),
+                feature_code: Some(FeatureCode::Ppl),
+                population: Some(30_000),
+                ..Default::default()
+            },
+            GeocodingResult {
+                id: Some(2),
+                feature_code: Some(FeatureCode::Ppla2),
+                population: Some(20_000),
+                ..Default::default()
+            },
+            GeocodingResult {
+                id: Some(3),
+                feature_code: Some(FeatureCode::Pplc),
+                population: Some(2_000_000),
+                ..Default::default()
+            },
+            GeocodingResult {
+                id: Some(4),
+                feature_code: Some(FeatureCode::Ppl),
+                population: Some(50_000),
+                ..Default::default()
+            },
+        ];
+        sort_by_relevance(&mut results);
+        let ids: Vec<_> = results.iter().filter_map(|r| r.id).collect();
+        assert_eq!(ids, [3, 2, 4, 1]);
+    }
+
+    #[test]
+    fn search_params() {
+        let params = Options::default()
+            .with_name("Paris".into())
+            .with_country_code("FR".into())
+            .with_format(Format::Protobuf)
+            .with_apikey("key".into())
+            .into_params();
+        assert!(params.contains(&("countryCode".into(), "FR".into())));
+        assert!(params.contains(&("format".into(), "protobuf".into())));
+        assert!(params.contains(&("apikey".into(), "key".into())));
+    }
+
+    #[test]
+    fn typed_results() {
+        let res: GeocodingResponse = serde_json::from_str(
+            r#"{"results": [
+                {"id": 2988507, "name": "Paris", "latitude": 48.85341, "longitude": 2.3488,
+                 "elevation": 42.0, "feature_code": "PPLC", "country_code": "FR",
+                 "timezone": "Europe/Paris", "population": 2138551},
+                {"id": 4717560, "name": "Paris", "latitude": 33.66094, "longitude": -95.55551,
+                 "feature_code": "PPLA2", "country_code": "US",
+                 "timezone": "America/Chicago", "population": 24782},
+                {"id": 1, "name": "Paris Lake", "feature_code": "LKS", "country_code": "US"},
+                {"id": 2, "name": "Paris Creek", "feature_code": "", "country_code": "US"},
+                {"id": 3, "name": "Paris Hill", "feature_code": null}
+            ]}"#,
+        )
+        .unwrap();
+        let codes: Vec<_> = res
+            .results
+            .iter()
+            .flatten()
+            .map(|r| r.feature_code.clone())
+            .collect();
+        assert_eq!(codes[2], Some(FeatureCode::Other("LKS".into())));
+        assert_eq!(codes[3..], [None, None]);
+
+        let results = res
+            .clone()
+            .filter(&Filter::default().with_min_population(100_000));
+        assert_eq!(results.len(), 1);
+        let paris = &results[0];
+        assert_eq!(paris.feature_code, Some(FeatureCode::Pplc));
+        assert_eq!(paris.timezone(), Some(chrono_tz::Europe::Paris));
+        assert_eq!(
+            paris.location(),
+            Some(location::Location {
+                lat: 48.85341,
+                lng: 2.3488
+            })
+        );
+        let opts = paris.forecast_options().unwrap();
+        assert_eq!(opts.time_zone.as_deref(), Some("Europe/Paris"));
+
+        let filter = Filter::default()
+            .with_country_code("us".into())
+            .with_feature_class(FeatureClass::PopulatedPlace);
+        let results = res.filter(&filter);
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, Some(4_717_560));
+        assert_eq!(
+            results[0]
+                .feature_code
+                .as_ref()
+                .and_then(FeatureCode::class),
+            Some(FeatureClass::PopulatedPlace)
+        );
+    }
+
+    #[test]
+    fn apply_keeps_caller_elevation() {
+        let opts = forecast::Options {
+            elevation: Some(forecast::Elevation::Nan),
+            ..Default::default()
+        };
+        let mut place = GeocodingResult {
+            latitude: Some(48.85),
+            longitude: Some(2.35),
+            ..Default::default()
+        };
+        let applied = place.apply_to(opts.clone()).unwrap();
+        assert!(matches!(applied.elevation, Some(forecast::Elevation::Nan)));
+
+        place.elevation = Some(42.0);
+        let applied = place.apply_to(opts).unwrap();
+        assert!(matches!(
+            applied.elevation,
+            Some(forecast::Elevation::Value(v)) if (v - 42.0).abs() < 1e-6
+        ));
+    }
 }
@@ -0,0 +1,369 @@
+//! Model grid geometry, used to snap locations to grid cells and to share one request between
+//! locations falling in the same cell.
+
+use super::{client, forecast, location, models::Model};
+use std::collections::HashMap;
+use std::error::Error;
+use std::f64::consts::FRAC_PI_4;
+
+const EARTH_RADIUS_M: f64 = 6_371_229.0;
+
+/// Geometry of a model grid
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Grid {
+    /// Regular latitude/longitude grid, `origin` is the center of the first cell
+    Regular {
+        lat_origin: f64,
+        lng_origin: f64,
+        lat_step: f64,
+        lng_step: f64,
+        ny: u32,
+        nx: u32,
+    },
+    /// Lambert conformal conic projection on a sphere, `origin` is the center of the first cell
+    LambertConformal {
+        standard_parallel_1: f64,
+        standard_parallel_2: f64,
+        reference_lat: f64,
+        reference_lng: f64,
+        lat_origin: f64,
+        lng_origin: f64,
+        dx_m: f64,
+        dy_m: f64,
+        nx: u32,
+        ny: u32,
+    },
+}
+
+/// Cell of a grid, `x` along longitudes and `y` along latitudes
+#[derive(Debug, Clone, PartialEq)]
+pub struct GridCell {
+    pub x: u32,
+    pub y: u32,
+    pub center: location::Location,
+}
+
+impl Grid {
+    /// Cell containing `location`, `None` outside of the grid
+    #[must_use]
+    pub fn cell(&self, location: &location::Location) -> Option<GridCell> {
+        match *self {
+            Self::Regular {
+                lat_origin,
+                lng_origin,
+                lat_step,
+                lng_step,
+                ny,
+                nx,
+            } => {
+                let is_global = f64::from(nx) * lng_step >= 360.0;
+                let mut lng_offset = location.lng - lng_origin;
+                if is_global {
+                    lng_offset = lng_offset.rem_euclid(360.0);
+                }
+                let x = index(lng_offset / lng_step, nx, is_global)?;
+                let y = index((location.lat - lat_origin) / lat_step, ny, false)?;
+
+                let mut lng = lng_origin + f64::from(x) * lng_step;
+                if lng > 180.0 {
+                    lng -= 360.0;
+                }
+                Some(GridCell {
+                    x,
+                    y,
+                    center: location::Location {
+                        lat: lat_origin + f64::from(y) * lat_step,
+                        lng,
+                    },
+                })
+            }
+            Self::LambertConformal {
+                dx_m,
+                dy_m,
+                nx,
+                ny,
+                lat_origin,
+                lng_origin,
+                ..
+            } => {
+                let (x0, y0) = self.project(lat_origin, lng_origin);
+                let (px, py) = self.project(location.lat, location.lng);
+                let x = index((px - x0) / dx_m, nx, false)?;
+                let y = index((py - y0) / dy_m, ny, false)?;
+                let (lat, lng) = self.unproject(x0 + f64::from(x) * dx_m, y0 + f64::from(y) * dy_m);
+
+                Some(GridCell {
+                    x,
+                    y,
+                    center: location::Location { lat, lng },
+                })
+            }
+        }
+    }
+
+    /// Cone constant, scale factor and radius at the reference latitude
+    fn lambert_constants(&self) -> (f64, f64, f64) {
+        let Self::LambertConformal {
+            standard_parallel_1,
+            standard_parallel_2,
+            reference_lat,
+            ..
+        } = *self
+        else {
+            return (1.0, 1.0, 0.0);
+        };
+
+        let (phi1, phi2) = (
+            standard_parallel_1.to_radians(),
+            standard_parallel_2.to_radians(),
+        );
+        let t = |phi: f64| (FRAC_PI_4 + phi / 2.0).tan();
+        let n = if (phi1 - phi2).abs() < 1e-10 {
+            phi1.sin()
+        } else {
+            (phi1.cos() / phi2.cos()).ln() / (t(phi2) / t(phi1)).ln()
+        };
+        let f = phi1.cos() * t(phi1).powf(n) / n;
+        let rho0 = EARTH_RADIUS_M * f / t(reference_lat.to_radians()).powf(n);
+        (n, f, rho0)
+    }
+
+    fn project(&self, lat: f64, lng: f64) -> (f64, f64) {
+        let Self::LambertConformal { reference_lng, .. } = *self else {
+            return (lng, lat);
+        };
+        let (n, f, rho0) = self.lambert_constants();
+        let rho = EARTH_RADIUS_M * f / (FRAC_PI_4 + lat.to_radians() / 2.0).tan().powf(n);
+        let theta = n * (lng - reference_lng).to_radians();
+        (rho * theta.sin(), rho0 - rho * theta.cos())
+    }
+
+    fn unproject(&self, x: f64, y: f64) -> (f64, f64) {
+        let Self::LambertConformal { reference_lng, .. } = *self else {
+            return (y, x);
+        };
+        let (n, f, rho0) = self.lambert_constants();
+        let rho = n.signum() * x.hypot(rho0 - y);
+        let theta = (n.signum() * x).atan2(n.signum() * (rho0 - y));
+        let lat = 2.0 * (EARTH_RADIUS_M * f / rho).powf(1.0 / n).atan() - 2.0 * FRAC_PI_4;
+        (lat.to_degrees(), reference_lng + (theta / n).to_degrees())
+    }
+}
+
+/// Nearest index, wrapped on global grids, `None` out of `0..count`
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn index(position: f64, count: u32, wrap: bool) -> Option<u32> {
+    let mut idx = position.round();
+    if wrap {
+        idx = idx.rem_euclid(f64::from(count));
+    }
+    (idx >= 0.0 && idx < f64::from(count)).then_some(idx as u32)
+}
+
+impl Model {
+    /// Grid of the model, `None` if the geometry is not known by this crate
+    #[must_use]
+    pub fn grid(&self) -> Option<Grid> {
+        let regular = |lat_origin, lng_origin, lat_step, lng_step, ny, nx| Grid::Regular {
+            lat_origin,
+            lng_origin,
+            lat_step,
+            lng_step,
+            ny,
+            nx,
+        };
+
+        match self {
+            Self::EcmwfIfs025
+            | Self::EcmwfAifs025
+            | Self::EcmwfAifs025Single
+            | Self::Gfs025
+            | Self::Era5 => Some(regular(-90.0, -180.0, 0.25, 0.25, 721, 1440)),
+            Self::EcmwfIfs04 | Self::CamsGlobal => Some(regular(-90.0, -180.0, 0.4, 0.4, 451, 900)),
+            Self::Era5Land => Some(regular(-90.0, -180.0, 0.1, 0.1, 1801, 3600)),
+            Self::IconEu => Some(regular(29.5, -23.5, 0.0625, 0.0625, 657, 1377)),
+            Self::IconD2 | Self::IconD2Ruc => Some(regular(43.18, -3.94, 0.02, 0.02, 746, 1215)),
+            Self::MeteofranceAromeFrance | Self::MeteofranceAromeFrance15min => {
+                Some(regular(37.5, -12.0, 0.025, 0.025, 717, 1121))
+            }
+            Self::JmaMsm => Some(regular(22.4, 120.0, 0.05, 0.0625, 505, 481)),
+            Self::CamsEurope => Some(regular(30.05, -24.95, 0.1, 0.1, 420, 700)),
+            Self::GfsHrrr => Some(Grid::LambertConformal {
+                standard_parallel_1: 38.5,
+                standard_parallel_2: 38.5,
+                reference_lat: 38.5,
+                reference_lng: -97.5,
+                lat_origin: 21.138_123,
+                lng_origin: -122.719_528,
+                dx_m: 3000.0,
+                dy_m: 3000.0,
+                nx: 1799,
+                ny: 1059,
+            }),
+            Self::MetnoNordic => Some(Grid::LambertConformal {
+                standard_parallel_1: 63.3,
+                standard_parallel_2: 63.3,
+                reference_lat: 63.3,
+                reference_lng: 15.0,
+                lat_origin: 50.319_616,
+                lng_origin: 0.278_280,
+                dx_m: 1000.0,
+                dy_m: 1000.0,
+                nx: 1796,
+                ny: 2321,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Group `locations` by grid cell. Each group holds the cell, or the location itself when
+/// the grid is unknown, and the indexes of the locations it serves. Without a grid only
+/// identical locations are grouped.
+#[must_use]
+pub fn group_by_cell(
+    locations: &[location::Location],
+    grid: Option<&Grid>,
+) -> Vec<(location::Location, Vec<usize>)> {
+    let mut groups: Vec<(location::Location, Vec<usize>)> = Vec::new();
+    let mut by_key: HashMap<(u64, u64), usize> = HashMap::new();
+
+    for (idx, location) in locations.iter().enumerate() {
+        let (key, center) = match grid.and_then(|grid| grid.cell(location)) {
+            Some(cell) => ((u64::from(cell.x), u64::from(cell.y)), cell.center),
+            None => (
+                (location.lat.to_bits(), location.lng.to_bits()),
+                location.clone(),
+            ),
+        };
+
+        if let Some(&group) = by_key.get(&key) {
+            groups[group].1.push(idx);
+        } else {
+            by_key.insert(key, groups.len());
+            groups.push((center, vec![idx]));
+        }
+    }
+
+    groups
+}
+
+impl client::Client {
+    /// Request a forecast for each of `locations` in a single multi-location request, with one
+    /// entry per grid cell. Results are returned in the order of `locations`, the metadata of
+    /// each gives the coordinates actually used by the API.
+    ///
+    /// Only the single model of `opts.models` is snapped, and only if its grid is built into
+    /// this crate (see [`Model::grid`]). Otherwise every distinct location is requested. Each
+    /// cell is requested at the coordinates of its first location, not at the cell center, so
+    /// the elevation downscaling and `opts.cell_selection` apply as for that location. Other
+    /// locations of the cell share its result.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if options are invalid, if api return an error or in case of network error.
+    pub async fn forecast_locations(
+        &self,
+        opts: forecast::Options,
+        locations: &[location::Location],
+    ) -> Result<Vec<forecast::ForecastResult>, Box<dyn Error>> {
+        let grid = match opts.models.as_deref() {
+            Some([model]) => model.grid(),
+            _ => None,
+        };
+        let groups = group_by_cell(locations, grid.as_ref());
+        if groups.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let requested: Vec<_> = groups
+            .iter()
+            .map(|(_, indexes)| locations[indexes[0]].clone())
+            .collect();
+        let responses = self.forecast_many(opts, &requested).await?;
+
+        let mut results = vec![None; locations.len()];
+        for ((_, indexes), res) in groups.into_iter().zip(responses) {
+            for idx in indexes {
+                results[idx] = Some(res.clone());
+            }
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snap_to_cells() {
+        let grid = Model::IconD2.grid().unwrap();
+        let cell = grid
+            .cell(&location::Location {
+                lat: 52.521,
+                lng: 13.409,
+            })
+            .unwrap();
+        assert!((cell.center.lat - 52.52).abs() < 1e-6);
+        assert!((cell.center.lng - 13.40).abs() < 1e-6);
+        assert!(grid
+            .cell(&location::Location {
+                lat: 40.0,
+                lng: 13.4
+            })
+            .is_none());
+
+        let global = Model::EcmwfIfs025.grid().unwrap();
+        let cell = global
+            .cell(&location::Location {
+                lat: 0.1,
+                lng: 179.9,
+            })
+            .unwrap();
+        assert_eq!((cell.x, cell.y), (0, 360));
+        assert!((cell.center.lng + 180.0).abs() < 1e-6);
+
+        let hrrr = Model::GfsHrrr.grid().unwrap();
+        let origin = hrrr
+            .cell(&location::Location {
+                lat: 21.138_123,
+                lng: -122.719_528,
+            })
+            .unwrap();
+        assert_eq!((origin.x, origin.y), (0, 0));
+        let new_york = hrrr
+            .cell(&location::Location {
+                lat: 40.71,
+                lng: -74.01,
+            })
+            .unwrap();
+        assert_eq!(hrrr.cell(&new_york.center), Some(new_york));
+    }
+
+    #[test]
+    fn group_locations() {
+        let locations = [
+            location::Location {
+                lat: 52.521,
+                lng: 13.409,
+            },
+            location::Location {
+                lat: 48.85,
+                lng: 2.35,
+            },
+            location::Location {
+                lat: 52.5205,
+                lng: 13.4005,
+            },
+        ];
+
+        let groups = group_by_cell(&locations, Model::IconD2.grid().as_ref());
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].1, vec![0, 2]);
+        assert_eq!(groups[1].1, vec![1]);
+
+        assert_eq!(group_by_cell(&locations, None).len(), 3);
+    }
+}
@@ -1,12 +1,32 @@
 #![deny(clippy::all, clippy::perf, clippy::complexity, clippy::pedantic)]
 
+mod builder;
 mod client;
 mod errors;
 mod location;
+mod query;
+mod validation;
 
+pub mod agro;
 pub mod air_quality;
+pub mod aqi;
+pub mod autocomplete;
+pub mod comparison;
+pub mod derive;
 pub mod forecast;
 pub mod geocoding;
+pub mod grid;
+pub mod models;
+pub mod pollen;
+pub mod pressure;
+pub mod resample;
+#[cfg(feature = "reverse-geocoding")]
+pub mod reverse_geocoding;
+pub mod series;
+pub mod solar;
+pub mod timeline;
+pub mod units;
+pub mod weather_code;
 
 pub use client::*;
 pub use errors::*;
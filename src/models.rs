@@ -0,0 +1,1613 @@
+//! Weather models accepted by the Open-Meteo APIs, with their metadata.
+
+use super::{errors, location};
+use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+
+/// Open-Meteo API
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Api {
+    Forecast,
+    Archive,
+    Ensemble,
+    Climate,
+    Marine,
+    Flood,
+    AirQuality,
+    Seasonal,
+    Satellite,
+}
+
+/// Section of a forecast response
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Section {
+    Current,
+    Minutely15,
+    Hourly,
+    Daily,
+}
+
+impl Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Current => write!(f, "current"),
+            Self::Minutely15 => write!(f, "minutely_15"),
+            Self::Hourly => write!(f, "hourly"),
+            Self::Daily => write!(f, "daily"),
+        }
+    }
+}
+
+const ALL_SECTIONS: &[Section] = &[
+    Section::Current,
+    Section::Minutely15,
+    Section::Hourly,
+    Section::Daily,
+];
+const HOURLY_SECTIONS: &[Section] = &[Section::Current, Section::Hourly, Section::Daily];
+
+/// Area covered by a regional model. Longitudes wrap when `min_lng` is greater than `max_lng`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoundingBox {
+    pub min_lat: f64,
+    pub min_lng: f64,
+    pub max_lat: f64,
+    pub max_lng: f64,
+}
+
+impl BoundingBox {
+    #[must_use]
+    pub const fn new(min_lat: f64, min_lng: f64, max_lat: f64, max_lng: f64) -> Self {
+        Self {
+            min_lat,
+            min_lng,
+            max_lat,
+            max_lng,
+        }
+    }
+
+    #[must_use]
+    pub fn contains(&self, location: &location::Location) -> bool {
+        let lng_inside = if self.min_lng <= self.max_lng {
+            (self.min_lng..=self.max_lng).contains(&location.lng)
+        } else {
+            location.lng >= self.min_lng || location.lng <= self.max_lng
+        };
+        lng_inside && (self.min_lat..=self.max_lat).contains(&location.lat)
+    }
+}
+
+/// Area covered by a regional model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Region {
+    BoundingBox(BoundingBox),
+    /// Domain that is not aligned on parallels and meridians, as `(lat, lng)` vertices
+    Polygon(&'static [(f64, f64)]),
+}
+
+impl Region {
+    #[must_use]
+    pub fn contains(&self, location: &location::Location) -> bool {
+        match self {
+            Self::BoundingBox(bbox) => bbox.contains(location),
+            Self::Polygon(polygon) => polygon_contains(polygon, location),
+        }
+    }
+}
+
+const fn bbox(min_lat: f64, min_lng: f64, max_lat: f64, max_lng: f64) -> Region {
+    Region::BoundingBox(BoundingBox::new(min_lat, min_lng, max_lat, max_lng))
+}
+
+const CONUS: Region = bbox(21.0, -134.0, 53.0, -60.0);
+const HRRR: Region =
+    Region::Polygon(&[(21.1, -122.7), (21.1, -72.3), (47.8, -60.9), (47.8, -134.1)]);
+const NAM_CONUS: Region =
+    Region::Polygon(&[(12.2, -133.5), (14.3, -65.1), (57.3, -49.4), (54.0, -152.9)]);
+const GEM_REGIONAL: Region =
+    Region::Polygon(&[(10.0, -150.0), (10.0, -60.0), (80.0, -10.0), (80.0, -180.0)]);
+const HRDPS_CONTINENTAL: Region =
+    Region::Polygon(&[(27.0, -125.0), (30.0, -65.0), (60.0, -40.0), (70.0, -152.0)]);
+const HRDPS_WEST: Region = bbox(40.0, -145.0, 65.0, -105.0);
+const ARPEGE_EUROPE: Region = bbox(20.0, -32.0, 72.0, 42.0);
+const AROME_FRANCE: Region = bbox(37.5, -12.0, 55.4, 16.0);
+const AROME_FRANCE_HD: Region = bbox(41.0, -5.5, 51.5, 10.0);
+const ICON_EU: Region = bbox(29.5, -23.5, 70.5, 62.5);
+const ICON_D2: Region = bbox(43.2, -3.9, 58.1, 20.3);
+const METNO_NORDIC: Region =
+    Region::Polygon(&[(54.0, 1.0), (54.0, 31.0), (71.5, 41.0), (73.0, -3.0)]);
+const KNMI_EUROPE: Region = bbox(39.0, -25.0, 64.0, 31.0);
+const KNMI_NETHERLANDS: Region = bbox(49.0, 0.0, 56.0, 11.0);
+const DMI_EUROPE: Region = bbox(39.0, -26.0, 75.0, 40.0);
+const UKMO_UK: Region = Region::Polygon(&[(47.5, -12.0), (47.5, 4.0), (61.0, 5.0), (61.0, -13.0)]);
+const COSMO_2I: Region = bbox(35.0, 5.0, 48.0, 20.0);
+const COSMO_5M: Region = bbox(30.0, -10.0, 55.0, 40.0);
+const ITALIA_METEO: Region = bbox(33.7, 3.0, 48.9, 22.0);
+const METEOSWISS: Region = bbox(43.5, 3.5, 49.5, 16.5);
+const JMA_MSM: Region = bbox(22.4, 120.0, 47.6, 150.0);
+const KMA_LDPS: Region = bbox(32.0, 122.0, 43.5, 132.5);
+const CERRA: Region = bbox(20.0, -58.0, 75.0, 74.0);
+const CAMS_EUROPE: Region = bbox(30.05, -24.95, 71.95, 44.95);
+const HIMAWARI: Region = bbox(-60.0, 80.0, 60.0, -160.0);
+const METEOSAT: Region = bbox(-65.0, -65.0, 65.0, 65.0);
+const METEOSAT_IODC: Region = bbox(-65.0, -20.0, 65.0, 110.0);
+
+/// Regional models, most preferred first
+const REGIONAL_PREFERENCE: &[Model] = &[
+    Model::MeteofranceAromeFranceHd,
+    Model::MetnoNordic,
+    Model::MeteoswissIconCh1,
+    Model::KnmiHarmonieAromeNetherlands,
+    Model::UkmoUkDeterministic2km,
+    Model::IconD2,
+    Model::KmaLdps,
+    Model::GfsHrrr,
+    Model::ItaliaMeteoArpaeIcon2i,
+    Model::DmiHarmonieAromeEurope,
+    Model::MeteofranceAromeFrance,
+    Model::MeteoswissIconCh2,
+    Model::GemHrdpsContinental,
+    Model::JmaMsm,
+    Model::NcepNamConus,
+    Model::KnmiHarmonieAromeEurope,
+    Model::IconEu,
+    Model::GemRegional,
+    Model::MeteofranceArpegeEurope,
+];
+
+/// Ray casting point in polygon test
+fn polygon_contains(polygon: &[(f64, f64)], location: &location::Location) -> bool {
+    let mut inside = false;
+    let mut previous = polygon[polygon.len() - 1];
+    for &vertex in polygon {
+        let ((lat1, lng1), (lat2, lng2)) = (vertex, previous);
+        if (lat1 > location.lat) != (lat2 > location.lat)
+            && location.lng < (lng2 - lng1) * (location.lat - lat1) / (lat2 - lat1) + lng1
+        {
+            inside = !inside;
+        }
+        previous = vertex;
+    }
+    inside
+}
+
+/// Static description of a model
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelInfo {
+    pub provider: &'static str,
+    /// Approximate grid spacing
+    pub resolution_km: f32,
+    /// Hours between runs, `None` if the dataset is not updated anymore
+    pub update_hours: Option<u16>,
+    /// Forecast length, `None` for reanalyses, satellite data and climate projections
+    pub forecast_hours: Option<u16>,
+    /// `None` for global models
+    pub region: Option<Region>,
+    /// APIs accepting the model
+    pub apis: &'static [Api],
+    /// Sections returned by the forecast API, `minutely_15` only for models with native
+    /// 15-minutely data and the seamless models combining them
+    pub sections: &'static [Section],
+}
+
+impl ModelInfo {
+    const fn new(
+        provider: &'static str,
+        resolution_km: f32,
+        update_hours: Option<u16>,
+        forecast_hours: Option<u16>,
+        region: Option<Region>,
+        apis: &'static [Api],
+    ) -> Self {
+        Self {
+            provider,
+            resolution_km,
+            update_hours,
+            forecast_hours,
+            region,
+            apis,
+            sections: HOURLY_SECTIONS,
+        }
+    }
+
+    /// Whether the model provides data at `location`
+    #[must_use]
+    pub fn covers(&self, location: &location::Location) -> bool {
+        self.region.is_none_or(|region| region.contains(location))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[non_exhaustive]
+pub enum Model {
+    BestMatch,
+    GfsSeamless,
+    GfsGlobal,
+    GfsHrrr,
+    MeteofranceSeamless,
+    MeteofranceArpegeSeamless,
+    MeteofranceArpegeWorld,
+    MeteofranceArpegeEurope,
+    MeteofranceAromeSeamless,
+    MeteofranceAromeFrance,
+    MeteofranceAromeFranceHd,
+    JmaSeamless,
+    JmaMsm,
+    JmaGsm,
+    GemSeamless,
+    GemGlobal,
+    GemRegional,
+    GemHrdpsContinental,
+    IconSeamless,
+    IconGlobal,
+    IconEu,
+    IconD2,
+    EcmwfIfs04,
+    MetnoNordic,
+    Era5Seamless,
+    Era5,
+    Cerra,
+    Era5Land,
+    EcmwfIfs,
+    Gwam,
+    Ewam,
+    GlofasSeamlessV3,
+    GlofasForecastV3,
+    GlofasConsolidatedV3,
+    GlofasSeamlessV4,
+    GlofasForecastV4,
+    GlofasConsolidatedV4,
+    Gfs025,
+    Gfs05,
+    CMCCCM2VHR4,
+    FGOALSF3HHighressst,
+    FGOALSF3H,
+    HiramSITHR,
+    MRIAGCM32S,
+    ECEarth3pHR,
+    MPIESM12XR,
+    NICAM168S,
+    CamsEurope,
+    CamsGlobal,
+    Cfsv2,
+    Era5Ocean,
+    CmaGrapesGlobal,
+    BomAccessGlobal,
+    BomAccessGlobalEnsemble,
+    ArpaeCosmoSeamless,
+    ArpaeCosmo2i,
+    ArpaeCosmo2iRuc,
+    ArpaeCosmo5m,
+    EcmwfIfs025,
+    EcmwfAifs025,
+    Gfs013,
+    GfsGraphcast025,
+    EcmwfWam025,
+    MeteofranceWave,
+    MeteofranceCurrents,
+    EcmwfWam025Ensemble,
+    NcepGfswave025,
+    NcepGefswave025,
+    KnmiSeamless,
+    KnmiHarmonieAromeEurope,
+    KnmiHarmonieAromeNetherlands,
+    DmiSeamless,
+    DmiHarmonieAromeEurope,
+    MetnoSeamless,
+    Era5Ensemble,
+    EcmwfIfsAnalysis,
+    EcmwfIfsLongWindow,
+    EcmwfIfsAnalysisLongWindow,
+    UkmoGlobalDeterministic10km,
+    UkmoUkDeterministic2km,
+    UkmoSeamless,
+    NcepGfswave016,
+    NcepNbmConus,
+    UkmoGlobalEnsemble20km,
+    EcmwfAifs025Single,
+    JmaJaxaHimawari,
+    EumetsatSarah3,
+    EumetsatLsaSafMsg,
+    EumetsatLsaSafIodc,
+    SatelliteRadiationSeamless,
+    KmaGdps,
+    KmaLdps,
+    KmaSeamless,
+    ItaliaMeteoArpaeIcon2i,
+    UkmoUkEnsemble2km,
+    MeteofranceAromeFranceHd15min,
+    MeteofranceAromeFrance15min,
+    MeteoswissIconCh1,
+    MeteoswissIconCh2,
+    MeteoswissIconCh1Ensemble,
+    MeteoswissIconCh2Ensemble,
+    MeteoswissIconSeamless,
+    NcepNamConus,
+    IconD2Ruc,
+    EcmwfSeas5,
+    EcmwfEc46,
+    EcmwfSeasonalSeamless,
+    EcmwfIfsSeamless,
+    JmaJaxaMtgFci,
+    GemHrdpsWest,
+    EcmwfWam,
+    NcepAigfs025,
+    NcepAigefs025,
+    NcepHgefs025EnsembleMean,
+    EcmwfSeasonalEnsembleMeanSeamless,
+    EcmwfSeas5EnsembleMean,
+    EcmwfEc46EnsembleMean,
+    NcepAigefs025EnsembleMean,
+    DwdIconEpsEnsembleMeanSeamless,
+    DwdIconEpsEnsembleMean,
+    DwdIconEuEpsEnsembleMean,
+    DwdIconD2EpsEnsembleMean,
+    NcepGefsEnsembleMeanSeamless,
+    NcepGefs025EnsembleMean,
+    NcepGefs05EnsembleMean,
+    EcmwfIfs025EnsembleMean,
+    EcmwfAifs025EnsembleMean,
+    MeteoswissIconCh1EnsembleMean,
+    MeteoswissIconCh2EnsembleMean,
+    CmcGemGepsEnsembleMean,
+    UkmoGlobalEnsembleMean20km,
+    UkmoUkEnsembleMean2km,
+    /// Model not known by this crate, passed as is to the API
+    Other(String),
+}
+
+impl Model {
+    /// Every model known by this crate
+    pub const ALL: &'static [Model] = &[
+        Self::BestMatch,
+        Self::GfsSeamless,
+        Self::GfsGlobal,
+        Self::GfsHrrr,
+        Self::MeteofranceSeamless,
+        Self::MeteofranceArpegeSeamless,
+        Self::MeteofranceArpegeWorld,
+        Self::MeteofranceArpegeEurope,
+        Self::MeteofranceAromeSeamless,
+        Self::MeteofranceAromeFrance,
+        Self::MeteofranceAromeFranceHd,
+        Self::JmaSeamless,
+        Self::JmaMsm,
+        Self::JmaGsm,
+        Self::GemSeamless,
+        Self::GemGlobal,
+        Self::GemRegional,
+        Self::GemHrdpsContinental,
+        Self::IconSeamless,
+        Self::IconGlobal,
+        Self::IconEu,
+        Self::IconD2,
+        Self::EcmwfIfs04,
+        Self::MetnoNordic,
+        Self::Era5Seamless,
+        Self::Era5,
+        Self::Cerra,
+        Self::Era5Land,
+        Self::EcmwfIfs,
+        Self::Gwam,
+        Self::Ewam,
+        Self::GlofasSeamlessV3,
+        Self::GlofasForecastV3,
+        Self::GlofasConsolidatedV3,
+        Self::GlofasSeamlessV4,
+        Self::GlofasForecastV4,
+        Self::GlofasConsolidatedV4,
+        Self::Gfs025,
+        Self::Gfs05,
+        Self::CMCCCM2VHR4,
+        Self::FGOALSF3HHighressst,
+        Self::FGOALSF3H,
+        Self::HiramSITHR,
+        Self::MRIAGCM32S,
+        Self::ECEarth3pHR,
+        Self::MPIESM12XR,
+        Self::NICAM168S,
+        Self::CamsEurope,
+        Self::CamsGlobal,
+        Self::Cfsv2,
+        Self::Era5Ocean,
+        Self::CmaGrapesGlobal,
+        Self::BomAccessGlobal,
+        Self::BomAccessGlobalEnsemble,
+        Self::ArpaeCosmoSeamless,
+        Self::ArpaeCosmo2i,
+        Self::ArpaeCosmo2iRuc,
+        Self::ArpaeCosmo5m,
+        Self::EcmwfIfs025,
+        Self::EcmwfAifs025,
+        Self::Gfs013,
+        Self::GfsGraphcast025,
+        Self::EcmwfWam025,
+        Self::MeteofranceWave,
+        Self::MeteofranceCurrents,
+        Self::EcmwfWam025Ensemble,
+        Self::NcepGfswave025,
+        Self::NcepGefswave025,
+        Self::KnmiSeamless,
+        Self::KnmiHarmonieAromeEurope,
+        Self::KnmiHarmonieAromeNetherlands,
+        Self::DmiSeamless,
+        Self::DmiHarmonieAromeEurope,
+        Self::MetnoSeamless,
+        Self::Era5Ensemble,
+        Self::EcmwfIfsAnalysis,
+        Self::EcmwfIfsLongWindow,
+        Self::EcmwfIfsAnalysisLongWindow,
+        Self::UkmoGlobalDeterministic10km,
+        Self::UkmoUkDeterministic2km,
+        Self::UkmoSeamless,
+        Self::NcepGfswave016,
+        Self::NcepNbmConus,
+        Self::UkmoGlobalEnsemble20km,
+        Self::EcmwfAifs025Single,
+        Self::JmaJaxaHimawari,
+        Self::EumetsatSarah3,
+        Self::EumetsatLsaSafMsg,
+        Self::EumetsatLsaSafIodc,
+        Self::SatelliteRadiationSeamless,
+        Self::KmaGdps,
+        Self::KmaLdps,
+        Self::KmaSeamless,
+        Self::ItaliaMeteoArpaeIcon2i,
+        Self::UkmoUkEnsemble2km,
+        Self::MeteofranceAromeFranceHd15min,
+        Self::MeteofranceAromeFrance15min,
+        Self::MeteoswissIconCh1,
+        Self::MeteoswissIconCh2,
+        Self::MeteoswissIconCh1Ensemble,
+        Self::MeteoswissIconCh2Ensemble,
+        Self::MeteoswissIconSeamless,
+        Self::NcepNamConus,
+        Self::IconD2Ruc,
+        Self::EcmwfSeas5,
+        Self::EcmwfEc46,
+        Self::EcmwfSeasonalSeamless,
+        Self::EcmwfIfsSeamless,
+        Self::JmaJaxaMtgFci,
+        Self::GemHrdpsWest,
+        Self::EcmwfWam,
+        Self::NcepAigfs025,
+        Self::NcepAigefs025,
+        Self::NcepHgefs025EnsembleMean,
+        Self::EcmwfSeasonalEnsembleMeanSeamless,
+        Self::EcmwfSeas5EnsembleMean,
+        Self::EcmwfEc46EnsembleMean,
+        Self::NcepAigefs025EnsembleMean,
+        Self::DwdIconEpsEnsembleMeanSeamless,
+        Self::DwdIconEpsEnsembleMean,
+        Self::DwdIconEuEpsEnsembleMean,
+        Self::DwdIconD2EpsEnsembleMean,
+        Self::NcepGefsEnsembleMeanSeamless,
+        Self::NcepGefs025EnsembleMean,
+        Self::NcepGefs05EnsembleMean,
+        Self::EcmwfIfs025EnsembleMean,
+        Self::EcmwfAifs025EnsembleMean,
+        Self::MeteoswissIconCh1EnsembleMean,
+        Self::MeteoswissIconCh2EnsembleMean,
+        Self::CmcGemGepsEnsembleMean,
+        Self::UkmoGlobalEnsembleMean20km,
+        Self::UkmoUkEnsembleMean2km,
+    ];
+
+    /// Known model named `name`, `None` for unknown names
+    #[must_use]
+    pub fn known(name: &str) -> Option<Self> {
+        Self::ALL.iter().find(|model| model.name() == name).cloned()
+    }
+
+    /// Name used by the API
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn name(&self) -> &str {
+        match self {
+            Self::BestMatch => "best_match",
+            Self::GfsSeamless => "gfs_seamless",
+            Self::GfsGlobal => "gfs_global",
+            Self::GfsHrrr => "gfs_hrrr",
+            Self::MeteofranceSeamless => "meteofrance_seamless",
+            Self::MeteofranceArpegeSeamless => "meteofrance_arpege_seamless",
+            Self::MeteofranceArpegeWorld => "meteofrance_arpege_world",
+            Self::MeteofranceArpegeEurope => "meteofrance_arpege_europe",
+            Self::MeteofranceAromeSeamless => "meteofrance_arome_seamless",
+            Self::MeteofranceAromeFrance => "meteofrance_arome_france",
+            Self::MeteofranceAromeFranceHd => "meteofrance_arome_france_hd",
+            Self::JmaSeamless => "jma_seamless",
+            Self::JmaMsm => "jma_msm",
+            Self::JmaGsm => "jma_gsm",
+            Self::GemSeamless => "gem_seamless",
+            Self::GemGlobal => "gem_global",
+            Self::GemRegional => "gem_regional",
+            Self::GemHrdpsContinental => "gem_hrdps_continental",
+            Self::IconSeamless => "icon_seamless",
+            Self::IconGlobal => "icon_global",
+            Self::IconEu => "icon_eu",
+            Self::IconD2 => "icon_d2",
+            Self::EcmwfIfs04 => "ecmwf_ifs04",
+            Self::MetnoNordic => "metno_nordic",
+            Self::Era5Seamless => "era5_seamless",
+            Self::Era5 => "era5",
+            Self::Cerra => "cerra",
+            Self::Era5Land => "era5_land",
+            Self::EcmwfIfs => "ecmwf_ifs",
+            Self::Gwam => "gwam",
+            Self::Ewam => "ewam",
+            Self::GlofasSeamlessV3 => "glofas_seamless_v3",
+            Self::GlofasForecastV3 => "glofas_forecast_v3",
+            Self::GlofasConsolidatedV3 => "glofas_consolidated_v3",
+            Self::GlofasSeamlessV4 => "glofas_seamless_v4",
+            Self::GlofasForecastV4 => "glofas_forecast_v4",
+            Self::GlofasConsolidatedV4 => "glofas_consolidated_v4",
+            Self::Gfs025 => "gfs025",
+            Self::Gfs05 => "gfs05",
+            Self::CMCCCM2VHR4 => "CMCC_CM2_VHR4",
+            Self::FGOALSF3HHighressst => "FGOALS_f3_H_highresSST",
+            Self::FGOALSF3H => "FGOALS_f3_H",
+            Self::HiramSITHR => "HiRAM_SIT_HR",
+            Self::MRIAGCM32S => "MRI_AGCM3_2_S",
+            Self::ECEarth3pHR => "EC_Earth3P_HR",
+            Self::MPIESM12XR => "MPI_ESM1_2_XR",
+            Self::NICAM168S => "NICAM16_8S",
+            Self::CamsEurope => "cams_europe",
+            Self::CamsGlobal => "cams_global",
+            Self::Cfsv2 => "cfsv2",
+            Self::Era5Ocean => "era5_ocean",
+            Self::CmaGrapesGlobal => "cma_grapes_global",
+            Self::BomAccessGlobal => "bom_access_global",
+            Self::BomAccessGlobalEnsemble => "bom_access_global_ensemble",
+            Self::ArpaeCosmoSeamless => "arpae_cosmo_seamless",
+            Self::ArpaeCosmo2i => "arpae_cosmo_2i",
+            Self::ArpaeCosmo2iRuc => "arpae_cosmo_2i_ruc",
+            Self::ArpaeCosmo5m => "arpae_cosmo_5m",
+            Self::EcmwfIfs025 => "ecmwf_ifs025",
+            Self::EcmwfAifs025 => "ecmwf_aifs025",
+            Self::Gfs013 => "gfs013",
+            Self::GfsGraphcast025 => "gfs_graphcast025",
+            Self::EcmwfWam025 => "ecmwf_wam025",
+            Self::MeteofranceWave => "meteofrance_wave",
+            Self::MeteofranceCurrents => "meteofrance_currents",
+            Self::EcmwfWam025Ensemble => "ecmwf_wam025_ensemble",
+            Self::NcepGfswave025 => "ncep_gfswave025",
+            Self::NcepGefswave025 => "ncep_gefswave025",
+            Self::KnmiSeamless => "knmi_seamless",
+            Self::KnmiHarmonieAromeEurope => "knmi_harmonie_arome_europe",
+            Self::KnmiHarmonieAromeNetherlands => "knmi_harmonie_arome_netherlands",
+            Self::DmiSeamless => "dmi_seamless",
+            Self::DmiHarmonieAromeEurope => "dmi_harmonie_arome_europe",
+            Self::MetnoSeamless => "metno_seamless",
+            Self::Era5Ensemble => "era5_ensemble",
+            Self::EcmwfIfsAnalysis => "ecmwf_ifs_analysis",
+            Self::EcmwfIfsLongWindow => "ecmwf_ifs_long_window",
+            Self::EcmwfIfsAnalysisLongWindow => "ecmwf_ifs_analysis_long_window",
+            Self::UkmoGlobalDeterministic10km => "ukmo_global_deterministic_10km",
+            Self::UkmoUkDeterministic2km => "ukmo_uk_deterministic_2km",
+            Self::UkmoSeamless => "ukmo_seamless",
+            Self::NcepGfswave016 => "ncep_gfswave016",
+            Self::NcepNbmConus => "ncep_nbm_conus",
+            Self::UkmoGlobalEnsemble20km => "ukmo_global_ensemble_20km",
+            Self::EcmwfAifs025Single => "ecmwf_aifs025_single",
+            Self::JmaJaxaHimawari => "jma_jaxa_himawari",
+            Self::EumetsatSarah3 => "eumetsat_sarah3",
+            Self::EumetsatLsaSafMsg => "eumetsat_lsa_saf_msg",
+            Self::EumetsatLsaSafIodc => "eumetsat_lsa_saf_iodc",
+            Self::SatelliteRadiationSeamless => "satellite_radiation_seamless",
+            Self::KmaGdps => "kma_gdps",
+            Self::KmaLdps => "kma_ldps",
+            Self::KmaSeamless => "kma_seamless",
+            Self::ItaliaMeteoArpaeIcon2i => "italia_meteo_arpae_icon_2i",
+            Self::UkmoUkEnsemble2km => "ukmo_uk_ensemble_2km",
+            Self::MeteofranceAromeFranceHd15min => "meteofrance_arome_france_hd_15min",
+            Self::MeteofranceAromeFrance15min => "meteofrance_arome_france_15min",
+            Self::MeteoswissIconCh1 => "meteoswiss_icon_ch1",
+            Self::MeteoswissIconCh2 => "meteoswiss_icon_ch2",
+            Self::MeteoswissIconCh1Ensemble => "meteoswiss_icon_ch1_ensemble",
+            Self::MeteoswissIconCh2Ensemble => "meteoswiss_icon_ch2_ensemble",
+            Self::MeteoswissIconSeamless => "meteoswiss_icon_seamless",
+            Self::NcepNamConus => "ncep_nam_conus",
+            Self::IconD2Ruc => "icon_d2_ruc",
+            Self::EcmwfSeas5 => "ecmwf_seas5",
+            Self::EcmwfEc46 => "ecmwf_ec46",
+            Self::EcmwfSeasonalSeamless => "ecmwf_seasonal_seamless",
+            Self::EcmwfIfsSeamless => "ecmwf_ifs_seamless",
+            Self::JmaJaxaMtgFci => "jma_jaxa_mtg_fci",
+            Self::GemHrdpsWest => "gem_hrdps_west",
+            Self::EcmwfWam => "ecmwf_wam",
+            Self::NcepAigfs025 => "ncep_aigfs025",
+            Self::NcepAigefs025 => "ncep_aigefs025",
+            Self::NcepHgefs025EnsembleMean => "ncep_hgefs025_ensemble_mean",
+            Self::EcmwfSeasonalEnsembleMeanSeamless => "ecmwf_seasonal_ensemble_mean_seamless",
+            Self::EcmwfSeas5EnsembleMean => "ecmwf_seas5_ensemble_mean",
+            Self::EcmwfEc46EnsembleMean => "ecmwf_ec46_ensemble_mean",
+            Self::NcepAigefs025EnsembleMean => "ncep_aigefs025_ensemble_mean",
+            Self::DwdIconEpsEnsembleMeanSeamless => "dwd_icon_eps_ensemble_mean_seamless",
+            Self::DwdIconEpsEnsembleMean => "dwd_icon_eps_ensemble_mean",
+            Self::DwdIconEuEpsEnsembleMean => "dwd_icon_eu_eps_ensemble_mean",
+            Self::DwdIconD2EpsEnsembleMean => "dwd_icon_d2_eps_ensemble_mean",
+            Self::NcepGefsEnsembleMeanSeamless => "ncep_gefs_ensemble_mean_seamless",
+            Self::NcepGefs025EnsembleMean => "ncep_gefs025_ensemble_mean",
+            Self::NcepGefs05EnsembleMean => "ncep_gefs05_ensemble_mean",
+            Self::EcmwfIfs025EnsembleMean => "ecmwf_ifs025_ensemble_mean",
+            Self::EcmwfAifs025EnsembleMean => "ecmwf_aifs025_ensemble_mean",
+            Self::MeteoswissIconCh1EnsembleMean => "meteoswiss_icon_ch1_ensemble_mean",
+            Self::MeteoswissIconCh2EnsembleMean => "meteoswiss_icon_ch2_ensemble_mean",
+            Self::CmcGemGepsEnsembleMean => "cmc_gem_geps_ensemble_mean",
+            Self::UkmoGlobalEnsembleMean20km => "ukmo_global_ensemble_mean_20km",
+            Self::UkmoUkEnsembleMean2km => "ukmo_uk_ensemble_mean_2km",
+            Self::Other(name) => name,
+        }
+    }
+
+    /// Metadata of the model, `None` for `Other`
+    #[must_use]
+    #[allow(clippy::too_many_lines, clippy::match_same_arms)]
+    pub fn info(&self) -> Option<ModelInfo> {
+        let info = match self {
+            Self::BestMatch => ModelInfo::new(
+                "Open-Meteo",
+                1.0,
+                Some(1),
+                Some(384),
+                None,
+                &[Api::Forecast, Api::Archive],
+            ),
+            Self::GfsSeamless => ModelInfo::new(
+                "NOAA NCEP",
+                3.0,
+                Some(1),
+                Some(384),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::GfsGlobal => ModelInfo::new(
+                "NOAA NCEP",
+                13.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::GfsHrrr => ModelInfo::new(
+                "NOAA NCEP",
+                3.0,
+                Some(1),
+                Some(48),
+                Some(HRRR),
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceSeamless => ModelInfo::new(
+                "Météo-France",
+                1.3,
+                Some(1),
+                Some(102),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceArpegeSeamless => ModelInfo::new(
+                "Météo-France",
+                11.0,
+                Some(6),
+                Some(102),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceArpegeWorld => ModelInfo::new(
+                "Météo-France",
+                25.0,
+                Some(6),
+                Some(102),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceArpegeEurope => ModelInfo::new(
+                "Météo-France",
+                11.0,
+                Some(6),
+                Some(102),
+                Some(ARPEGE_EUROPE),
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceAromeSeamless => ModelInfo::new(
+                "Météo-France",
+                1.3,
+                Some(1),
+                Some(51),
+                Some(AROME_FRANCE),
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceAromeFrance => ModelInfo::new(
+                "Météo-France",
+                2.5,
+                Some(1),
+                Some(51),
+                Some(AROME_FRANCE),
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceAromeFranceHd => ModelInfo::new(
+                "Météo-France",
+                1.3,
+                Some(1),
+                Some(51),
+                Some(AROME_FRANCE_HD),
+                &[Api::Forecast],
+            ),
+            Self::JmaSeamless => {
+                ModelInfo::new("JMA", 5.0, Some(3), Some(264), None, &[Api::Forecast])
+            }
+            Self::JmaMsm => ModelInfo::new(
+                "JMA",
+                5.0,
+                Some(3),
+                Some(78),
+                Some(JMA_MSM),
+                &[Api::Forecast],
+            ),
+            Self::JmaGsm => ModelInfo::new("JMA", 55.0, Some(6), Some(264), None, &[Api::Forecast]),
+            Self::GemSeamless => {
+                ModelInfo::new("ECCC", 2.5, Some(6), Some(240), None, &[Api::Forecast])
+            }
+            Self::GemGlobal => ModelInfo::new(
+                "ECCC",
+                15.0,
+                Some(12),
+                Some(240),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::GemRegional => ModelInfo::new(
+                "ECCC",
+                10.0,
+                Some(6),
+                Some(84),
+                Some(GEM_REGIONAL),
+                &[Api::Forecast],
+            ),
+            Self::GemHrdpsContinental => ModelInfo::new(
+                "ECCC",
+                2.5,
+                Some(6),
+                Some(48),
+                Some(HRDPS_CONTINENTAL),
+                &[Api::Forecast],
+            ),
+            Self::IconSeamless => ModelInfo::new(
+                "DWD",
+                2.0,
+                Some(3),
+                Some(180),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::IconGlobal => ModelInfo::new(
+                "DWD",
+                11.0,
+                Some(6),
+                Some(180),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::IconEu => ModelInfo::new(
+                "DWD",
+                7.0,
+                Some(3),
+                Some(120),
+                Some(ICON_EU),
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::IconD2 => ModelInfo::new(
+                "DWD",
+                2.0,
+                Some(3),
+                Some(48),
+                Some(ICON_D2),
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::EcmwfIfs04 => ModelInfo::new(
+                "ECMWF",
+                44.0,
+                Some(6),
+                Some(240),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::MetnoNordic => ModelInfo::new(
+                "MET Norway",
+                1.0,
+                Some(1),
+                Some(61),
+                Some(METNO_NORDIC),
+                &[Api::Forecast],
+            ),
+            Self::Era5Seamless => {
+                ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive])
+            }
+            Self::Era5 => ModelInfo::new("ECMWF", 25.0, Some(24), None, None, &[Api::Archive]),
+            Self::Cerra => ModelInfo::new("ECMWF", 5.0, None, None, Some(CERRA), &[Api::Archive]),
+            Self::Era5Land => ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive]),
+            Self::EcmwfIfs => ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive]),
+            Self::Gwam => ModelInfo::new("DWD", 25.0, Some(12), Some(180), None, &[Api::Marine]),
+            Self::Ewam => ModelInfo::new(
+                "DWD",
+                5.0,
+                Some(12),
+                Some(78),
+                Some(ICON_EU),
+                &[Api::Marine],
+            ),
+            Self::GlofasSeamlessV3 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                Some(720),
+                None,
+                &[Api::Flood],
+            ),
+            Self::GlofasForecastV3 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                Some(720),
+                None,
+                &[Api::Flood],
+            ),
+            Self::GlofasConsolidatedV3 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                None,
+                None,
+                &[Api::Flood],
+            ),
+            Self::GlofasSeamlessV4 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                Some(720),
+                None,
+                &[Api::Flood],
+            ),
+            Self::GlofasForecastV4 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                Some(720),
+                None,
+                &[Api::Flood],
+            ),
+            Self::GlofasConsolidatedV4 => ModelInfo::new(
+                "Copernicus GloFAS",
+                5.0,
+                Some(24),
+                None,
+                None,
+                &[Api::Flood],
+            ),
+            Self::Gfs025 => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(840),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::Gfs05 => ModelInfo::new(
+                "NOAA NCEP",
+                50.0,
+                Some(6),
+                Some(840),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::CMCCCM2VHR4 => ModelInfo::new("CMCC", 30.0, None, None, None, &[Api::Climate]),
+            Self::FGOALSF3HHighressst => {
+                ModelInfo::new("CAS", 28.0, None, None, None, &[Api::Climate])
+            }
+            Self::FGOALSF3H => ModelInfo::new("CAS", 28.0, None, None, None, &[Api::Climate]),
+            Self::HiramSITHR => ModelInfo::new("AS-RCEC", 25.0, None, None, None, &[Api::Climate]),
+            Self::MRIAGCM32S => ModelInfo::new("MRI", 20.0, None, None, None, &[Api::Climate]),
+            Self::ECEarth3pHR => {
+                ModelInfo::new("EC-Earth", 29.0, None, None, None, &[Api::Climate])
+            }
+            Self::MPIESM12XR => ModelInfo::new("MPI-M", 51.0, None, None, None, &[Api::Climate]),
+            Self::NICAM168S => {
+                ModelInfo::new("AORI JAMSTEC", 31.0, None, None, None, &[Api::Climate])
+            }
+            Self::CamsEurope => ModelInfo::new(
+                "ECMWF CAMS",
+                11.0,
+                Some(24),
+                Some(96),
+                Some(CAMS_EUROPE),
+                &[Api::AirQuality],
+            ),
+            Self::CamsGlobal => ModelInfo::new(
+                "ECMWF CAMS",
+                44.0,
+                Some(12),
+                Some(120),
+                None,
+                &[Api::AirQuality],
+            ),
+            Self::Cfsv2 => ModelInfo::new(
+                "NOAA NCEP",
+                100.0,
+                Some(6),
+                Some(6552),
+                None,
+                &[Api::Seasonal],
+            ),
+            Self::Era5Ocean => ModelInfo::new("ECMWF", 50.0, Some(24), None, None, &[Api::Marine]),
+            Self::CmaGrapesGlobal => {
+                ModelInfo::new("CMA", 15.0, Some(6), Some(240), None, &[Api::Forecast])
+            }
+            Self::BomAccessGlobal => {
+                ModelInfo::new("BOM", 15.0, Some(6), Some(240), None, &[Api::Forecast])
+            }
+            Self::BomAccessGlobalEnsemble => {
+                ModelInfo::new("BOM", 40.0, Some(6), Some(240), None, &[Api::Ensemble])
+            }
+            Self::ArpaeCosmoSeamless => ModelInfo::new(
+                "ARPAE",
+                2.2,
+                Some(3),
+                Some(72),
+                Some(COSMO_5M),
+                &[Api::Forecast],
+            ),
+            Self::ArpaeCosmo2i => ModelInfo::new(
+                "ARPAE",
+                2.2,
+                Some(3),
+                Some(48),
+                Some(COSMO_2I),
+                &[Api::Forecast],
+            ),
+            Self::ArpaeCosmo2iRuc => ModelInfo::new(
+                "ARPAE",
+                2.2,
+                Some(1),
+                Some(18),
+                Some(COSMO_2I),
+                &[Api::Forecast],
+            ),
+            Self::ArpaeCosmo5m => ModelInfo::new(
+                "ARPAE",
+                5.0,
+                Some(12),
+                Some(72),
+                Some(COSMO_5M),
+                &[Api::Forecast],
+            ),
+            Self::EcmwfIfs025 => ModelInfo::new(
+                "ECMWF",
+                25.0,
+                Some(6),
+                Some(360),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::EcmwfAifs025 => ModelInfo::new(
+                "ECMWF",
+                25.0,
+                Some(6),
+                Some(360),
+                None,
+                &[Api::Forecast, Api::Ensemble],
+            ),
+            Self::Gfs013 => ModelInfo::new(
+                "NOAA NCEP",
+                13.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::GfsGraphcast025 => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::EcmwfWam025 => {
+                ModelInfo::new("ECMWF", 25.0, Some(6), Some(360), None, &[Api::Marine])
+            }
+            Self::MeteofranceWave => ModelInfo::new(
+                "Météo-France",
+                10.0,
+                Some(12),
+                Some(240),
+                None,
+                &[Api::Marine],
+            ),
+            Self::MeteofranceCurrents => ModelInfo::new(
+                "Météo-France",
+                8.0,
+                Some(24),
+                Some(240),
+                None,
+                &[Api::Marine],
+            ),
+            Self::EcmwfWam025Ensemble => {
+                ModelInfo::new("ECMWF", 25.0, Some(6), Some(360), None, &[Api::Marine])
+            }
+            Self::NcepGfswave025 => {
+                ModelInfo::new("NOAA NCEP", 25.0, Some(6), Some(384), None, &[Api::Marine])
+            }
+            Self::NcepGefswave025 => {
+                ModelInfo::new("NOAA NCEP", 25.0, Some(6), Some(384), None, &[Api::Marine])
+            }
+            Self::KnmiSeamless => {
+                ModelInfo::new("KNMI", 2.0, Some(1), Some(60), None, &[Api::Forecast])
+            }
+            Self::KnmiHarmonieAromeEurope => ModelInfo::new(
+                "KNMI",
+                5.5,
+                Some(1),
+                Some(60),
+                Some(KNMI_EUROPE),
+                &[Api::Forecast],
+            ),
+            Self::KnmiHarmonieAromeNetherlands => ModelInfo::new(
+                "KNMI",
+                2.0,
+                Some(1),
+                Some(60),
+                Some(KNMI_NETHERLANDS),
+                &[Api::Forecast],
+            ),
+            Self::DmiSeamless => {
+                ModelInfo::new("DMI", 2.0, Some(3), Some(60), None, &[Api::Forecast])
+            }
+            Self::DmiHarmonieAromeEurope => ModelInfo::new(
+                "DMI",
+                2.0,
+                Some(3),
+                Some(60),
+                Some(DMI_EUROPE),
+                &[Api::Forecast],
+            ),
+            Self::MetnoSeamless => {
+                ModelInfo::new("MET Norway", 1.0, Some(1), Some(61), None, &[Api::Forecast])
+            }
+            Self::Era5Ensemble => {
+                ModelInfo::new("ECMWF", 50.0, Some(24), None, None, &[Api::Archive])
+            }
+            Self::EcmwfIfsAnalysis => {
+                ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive])
+            }
+            Self::EcmwfIfsLongWindow => {
+                ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive])
+            }
+            Self::EcmwfIfsAnalysisLongWindow => {
+                ModelInfo::new("ECMWF", 9.0, Some(24), None, None, &[Api::Archive])
+            }
+            Self::UkmoGlobalDeterministic10km => ModelInfo::new(
+                "UK Met Office",
+                10.0,
+                Some(6),
+                Some(168),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::UkmoUkDeterministic2km => ModelInfo::new(
+                "UK Met Office",
+                2.0,
+                Some(1),
+                Some(120),
+                Some(UKMO_UK),
+                &[Api::Forecast],
+            ),
+            Self::UkmoSeamless => ModelInfo::new(
+                "UK Met Office",
+                2.0,
+                Some(1),
+                Some(168),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::NcepGfswave016 => {
+                ModelInfo::new("NOAA NCEP", 16.0, Some(6), Some(384), None, &[Api::Marine])
+            }
+            Self::NcepNbmConus => ModelInfo::new(
+                "NOAA NCEP",
+                2.5,
+                Some(1),
+                Some(264),
+                Some(CONUS),
+                &[Api::Forecast],
+            ),
+            Self::UkmoGlobalEnsemble20km => ModelInfo::new(
+                "UK Met Office",
+                20.0,
+                Some(6),
+                Some(192),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::EcmwfAifs025Single => {
+                ModelInfo::new("ECMWF", 25.0, Some(6), Some(360), None, &[Api::Forecast])
+            }
+            Self::JmaJaxaHimawari => ModelInfo::new(
+                "JMA JAXA",
+                5.0,
+                Some(1),
+                None,
+                Some(HIMAWARI),
+                &[Api::Satellite],
+            ),
+            Self::EumetsatSarah3 => ModelInfo::new(
+                "EUMETSAT",
+                5.0,
+                Some(24),
+                None,
+                Some(METEOSAT),
+                &[Api::Satellite],
+            ),
+            Self::EumetsatLsaSafMsg => ModelInfo::new(
+                "EUMETSAT",
+                5.0,
+                Some(1),
+                None,
+                Some(METEOSAT),
+                &[Api::Satellite],
+            ),
+            Self::EumetsatLsaSafIodc => ModelInfo::new(
+                "EUMETSAT",
+                5.0,
+                Some(1),
+                None,
+                Some(METEOSAT_IODC),
+                &[Api::Satellite],
+            ),
+            Self::SatelliteRadiationSeamless => {
+                ModelInfo::new("Open-Meteo", 5.0, Some(1), None, None, &[Api::Satellite])
+            }
+            Self::KmaGdps => {
+                ModelInfo::new("KMA", 12.0, Some(6), Some(288), None, &[Api::Forecast])
+            }
+            Self::KmaLdps => ModelInfo::new(
+                "KMA",
+                1.5,
+                Some(3),
+                Some(48),
+                Some(KMA_LDPS),
+                &[Api::Forecast],
+            ),
+            Self::KmaSeamless => {
+                ModelInfo::new("KMA", 1.5, Some(3), Some(288), None, &[Api::Forecast])
+            }
+            Self::ItaliaMeteoArpaeIcon2i => ModelInfo::new(
+                "ItaliaMeteo ARPAE",
+                2.0,
+                Some(3),
+                Some(72),
+                Some(ITALIA_METEO),
+                &[Api::Forecast],
+            ),
+            Self::UkmoUkEnsemble2km => ModelInfo::new(
+                "UK Met Office",
+                2.0,
+                Some(1),
+                Some(120),
+                Some(UKMO_UK),
+                &[Api::Ensemble],
+            ),
+            Self::MeteofranceAromeFranceHd15min => ModelInfo::new(
+                "Météo-France",
+                1.3,
+                Some(1),
+                Some(48),
+                Some(AROME_FRANCE_HD),
+                &[Api::Forecast],
+            ),
+            Self::MeteofranceAromeFrance15min => ModelInfo::new(
+                "Météo-France",
+                2.5,
+                Some(1),
+                Some(48),
+                Some(AROME_FRANCE),
+                &[Api::Forecast],
+            ),
+            Self::MeteoswissIconCh1 => ModelInfo::new(
+                "MeteoSwiss",
+                1.0,
+                Some(3),
+                Some(33),
+                Some(METEOSWISS),
+                &[Api::Forecast],
+            ),
+            Self::MeteoswissIconCh2 => ModelInfo::new(
+                "MeteoSwiss",
+                2.0,
+                Some(6),
+                Some(120),
+                Some(METEOSWISS),
+                &[Api::Forecast],
+            ),
+            Self::MeteoswissIconCh1Ensemble => ModelInfo::new(
+                "MeteoSwiss",
+                1.0,
+                Some(3),
+                Some(33),
+                Some(METEOSWISS),
+                &[Api::Ensemble],
+            ),
+            Self::MeteoswissIconCh2Ensemble => ModelInfo::new(
+                "MeteoSwiss",
+                2.0,
+                Some(6),
+                Some(120),
+                Some(METEOSWISS),
+                &[Api::Ensemble],
+            ),
+            Self::MeteoswissIconSeamless => ModelInfo::new(
+                "MeteoSwiss",
+                1.0,
+                Some(3),
+                Some(120),
+                Some(METEOSWISS),
+                &[Api::Forecast],
+            ),
+            Self::NcepNamConus => ModelInfo::new(
+                "NOAA NCEP",
+                3.0,
+                Some(6),
+                Some(60),
+                Some(NAM_CONUS),
+                &[Api::Forecast],
+            ),
+            Self::IconD2Ruc => ModelInfo::new(
+                "DWD",
+                2.0,
+                Some(1),
+                Some(12),
+                Some(ICON_D2),
+                &[Api::Forecast],
+            ),
+            Self::EcmwfSeas5 => {
+                ModelInfo::new("ECMWF", 36.0, Some(720), Some(5160), None, &[Api::Seasonal])
+            }
+            Self::EcmwfEc46 => {
+                ModelInfo::new("ECMWF", 36.0, Some(24), Some(1104), None, &[Api::Seasonal])
+            }
+            Self::EcmwfSeasonalSeamless => {
+                ModelInfo::new("ECMWF", 36.0, Some(24), Some(5160), None, &[Api::Seasonal])
+            }
+            Self::EcmwfIfsSeamless => {
+                ModelInfo::new("ECMWF", 9.0, Some(6), Some(360), None, &[Api::Forecast])
+            }
+            Self::JmaJaxaMtgFci => ModelInfo::new(
+                "EUMETSAT",
+                5.0,
+                Some(1),
+                None,
+                Some(METEOSAT),
+                &[Api::Satellite],
+            ),
+            Self::GemHrdpsWest => ModelInfo::new(
+                "ECCC",
+                1.0,
+                Some(6),
+                Some(48),
+                Some(HRDPS_WEST),
+                &[Api::Forecast],
+            ),
+            Self::EcmwfWam => {
+                ModelInfo::new("ECMWF", 9.0, Some(6), Some(240), None, &[Api::Marine])
+            }
+            Self::NcepAigfs025 => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Forecast],
+            ),
+            Self::NcepAigefs025 => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::NcepHgefs025EnsembleMean => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::EcmwfSeasonalEnsembleMeanSeamless => {
+                ModelInfo::new("ECMWF", 36.0, Some(24), Some(5160), None, &[Api::Seasonal])
+            }
+            Self::EcmwfSeas5EnsembleMean => {
+                ModelInfo::new("ECMWF", 36.0, Some(720), Some(5160), None, &[Api::Seasonal])
+            }
+            Self::EcmwfEc46EnsembleMean => {
+                ModelInfo::new("ECMWF", 36.0, Some(24), Some(1104), None, &[Api::Seasonal])
+            }
+            Self::NcepAigefs025EnsembleMean => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(384),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::DwdIconEpsEnsembleMeanSeamless => {
+                ModelInfo::new("DWD", 26.0, Some(6), Some(180), None, &[Api::Ensemble])
+            }
+            Self::DwdIconEpsEnsembleMean => {
+                ModelInfo::new("DWD", 26.0, Some(6), Some(180), None, &[Api::Ensemble])
+            }
+            Self::DwdIconEuEpsEnsembleMean => ModelInfo::new(
+                "DWD",
+                13.0,
+                Some(6),
+                Some(120),
+                Some(ICON_EU),
+                &[Api::Ensemble],
+            ),
+            Self::DwdIconD2EpsEnsembleMean => ModelInfo::new(
+                "DWD",
+                2.0,
+                Some(3),
+                Some(48),
+                Some(ICON_D2),
+                &[Api::Ensemble],
+            ),
+            Self::NcepGefsEnsembleMeanSeamless => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(840),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::NcepGefs025EnsembleMean => ModelInfo::new(
+                "NOAA NCEP",
+                25.0,
+                Some(6),
+                Some(240),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::NcepGefs05EnsembleMean => ModelInfo::new(
+                "NOAA NCEP",
+                50.0,
+                Some(6),
+                Some(840),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::EcmwfIfs025EnsembleMean => {
+                ModelInfo::new("ECMWF", 25.0, Some(6), Some(360), None, &[Api::Ensemble])
+            }
+            Self::EcmwfAifs025EnsembleMean => {
+                ModelInfo::new("ECMWF", 25.0, Some(6), Some(360), None, &[Api::Ensemble])
+            }
+            Self::MeteoswissIconCh1EnsembleMean => ModelInfo::new(
+                "MeteoSwiss",
+                1.0,
+                Some(3),
+                Some(33),
+                Some(METEOSWISS),
+                &[Api::Ensemble],
+            ),
+            Self::MeteoswissIconCh2EnsembleMean => ModelInfo::new(
+                "MeteoSwiss",
+                2.0,
+                Some(6),
+                Some(120),
+                Some(METEOSWISS),
+                &[Api::Ensemble],
+            ),
+            Self::CmcGemGepsEnsembleMean => {
+                ModelInfo::new("ECCC", 25.0, Some(12), Some(384), None, &[Api::Ensemble])
+            }
+            Self::UkmoGlobalEnsembleMean20km => ModelInfo::new(
+                "UK Met Office",
+                20.0,
+                Some(6),
+                Some(192),
+                None,
+                &[Api::Ensemble],
+            ),
+            Self::UkmoUkEnsembleMean2km => ModelInfo::new(
+                "UK Met Office",
+                2.0,
+                Some(1),
+                Some(120),
+                Some(UKMO_UK),
+                &[Api::Ensemble],
+            ),
+            Self::Other(_) => return None,
+        };
+
+        let sections = match self {
+            Self::BestMatch
+            | Self::GfsSeamless
+            | Self::GfsHrrr
+            | Self::NcepNbmConus
+            | Self::IconSeamless
+            | Self::IconD2
+            | Self::IconD2Ruc
+            | Self::MeteofranceSeamless
+            | Self::MeteofranceAromeSeamless
+            | Self::MeteofranceAromeFrance15min
+            | Self::MeteofranceAromeFranceHd15min => ALL_SECTIONS,
+            _ => HOURLY_SECTIONS,
+        };
+        Some(ModelInfo { sections, ..info })
+    }
+}
+
+impl Model {
+    /// Whether `api` accepts the model. Unknown models are never supported, add them to
+    /// [`Model`] to use them.
+    #[must_use]
+    pub fn supports(&self, api: Api) -> bool {
+        self.info().is_some_and(|info| info.apis.contains(&api))
+    }
+
+    /// Whether the forecast API returns `section` for the model
+    #[must_use]
+    pub fn supports_section(&self, section: Section) -> bool {
+        self.info()
+            .is_some_and(|info| info.sections.contains(&section))
+    }
+
+    /// Known models providing data at `location`
+    #[must_use]
+    pub fn covering(location: &location::Location) -> Vec<Model> {
+        Self::ALL
+            .iter()
+            .filter(|model| model.info().is_some_and(|info| info.covers(location)))
+            .cloned()
+            .collect()
+    }
+
+    /// High resolution regional models of the forecast API available at `location`, most
+    /// preferred first. Empty if no regional model covers the location.
+    ///
+    /// ```
+    /// use open_meteo_rs::forecast::Model;
+    /// use open_meteo_rs::Location;
+    ///
+    /// let paris = Location { lat: 48.85, lng: 2.35 };
+    /// let models = Model::best_regional(&paris);
+    /// assert_eq!(models[0], Model::MeteofranceAromeFranceHd);
+    /// ```
+    #[must_use]
+    pub fn best_regional(location: &location::Location) -> Vec<Model> {
+        REGIONAL_PREFERENCE
+            .iter()
+            .filter(|model| {
+                model
+                    .info()
+                    .is_some_and(|info| info.region.is_some_and(|r| r.contains(location)))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
+impl Display for Model {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl From<Model> for String {
+    fn from(value: Model) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for Model {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        // "auto" is not a model, the API fails instead of selecting one
+        if value.is_empty() || value == "auto" {
+            return Err(errors::ConversionError::InvalidModel {
+                model: value.to_string(),
+            });
+        }
+
+        Ok(Self::known(value).unwrap_or_else(|| Self::Other(value.to_string())))
+    }
+}
+
+impl TryFrom<String> for Model {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::try_from(value.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_models() {
+        assert_eq!(Model::try_from("icon_d2").unwrap(), Model::IconD2);
+        assert_eq!(
+            Model::try_from("future_model").unwrap(),
+            Model::Other("future_model".into())
+        );
+        assert!(Model::try_from("").is_err());
+        assert!(Model::try_from("auto").is_err());
+
+        for model in Model::ALL {
+            assert_eq!(&Model::try_from(model.to_string()).unwrap(), model);
+            assert!(model.info().is_some());
+        }
+    }
+
+    #[test]
+    fn models_covering_location() {
+        let berlin = location::Location::default();
+        let new_york = location::Location {
+            lat: 40.71,
+            lng: -74.01,
+        };
+
+        let models = Model::covering(&berlin);
+        assert!(models.contains(&Model::IconD2));
+        assert!(models.contains(&Model::EcmwfIfs025));
+        assert!(!models.contains(&Model::GfsHrrr));
+
+        let models = Model::covering(&new_york);
+        assert!(models.contains(&Model::GfsHrrr));
+        assert!(!models.contains(&Model::IconD2));
+
+        assert!(Model::Era5.supports(Api::Archive));
+        assert!(!Model::Era5.supports(Api::Forecast));
+        assert!(!Model::Other("new".into()).supports(Api::Forecast));
+    }
+
+    #[test]
+    fn best_regional_models() {
+        let best = |lat, lng| Model::best_regional(&location::Location { lat, lng });
+
+        assert_eq!(best(52.52, 13.41)[0], Model::IconD2);
+        assert_eq!(best(40.71, -74.01)[0], Model::GfsHrrr);
+        assert_eq!(best(35.68, 139.69)[0], Model::JmaMsm);
+        assert_eq!(best(59.91, 10.75)[0], Model::MetnoNordic);
+
+        // Amsterdam is covered by nested domains, ordered by preference
+        let models = best(52.37, 4.9);
+        assert_eq!(
+            models,
+            [
+                Model::KnmiHarmonieAromeNetherlands,
+                Model::IconD2,
+                Model::DmiHarmonieAromeEurope,
+                Model::MeteofranceAromeFrance,
+                Model::KnmiHarmonieAromeEurope,
+                Model::IconEu,
+                Model::MeteofranceArpegeEurope,
+            ]
+        );
+
+        // Inside the bounding box of the MET Nordic polygon but outside the polygon
+        let models = best(54.5, 38.0);
+        assert!(!models.contains(&Model::MetnoNordic));
+        assert_eq!(models[0], Model::DmiHarmonieAromeEurope);
+
+        // Outside every regional domain
+        for (lat, lng) in [(-33.9, 18.4), (0.0, -150.0), (-80.0, 0.0)] {
+            assert!(best(lat, lng).is_empty());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+//! Pollen levels and daily peak alerts per species.
+//!
+//! Levels follow the thresholds of the National Allergy Bureau, in grains/m³: trees (alder,
+//! birch, olive), grasses and weeds (mugwort, ragweed) each have their own scale.
+//!
+//! ```no_run
+//! # async fn run(client: open_meteo_rs::Client) -> Result<(), Box<dyn std::error::Error>> {
+//! use open_meteo_rs::air_quality::AirQualityRequest;
+//! use open_meteo_rs::pollen::{self, PollenLevel};
+//!
+//! let opts = AirQualityRequest::at(48.85, 2.35).pollen().build()?;
+//! let res = client.air_quality(opts).await?;
+//!
+//! for alert in pollen::alerts(res.hourly.as_deref().unwrap_or_default(), PollenLevel::High) {
+//!     println!("{}: {} pollen {}", alert.date, alert.species, alert.peak.level);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::air_quality::{AirQualityResult, PollenSpecies};
+use crate::forecast::ForecastResultHourly;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+/// Concentration level, ordered from `None` to `VeryHigh`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PollenLevel {
+    None,
+    Low,
+    Moderate,
+    High,
+    VeryHigh,
+}
+
+impl Display for PollenLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Low => write!(f, "low"),
+            Self::Moderate => write!(f, "moderate"),
+            Self::High => write!(f, "high"),
+            Self::VeryHigh => write!(f, "very high"),
+        }
+    }
+}
+
+impl PollenSpecies {
+    /// Lower bounds of the low, moderate, high and very high levels, in grains/m³
+    #[must_use]
+    pub fn thresholds(self) -> [f64; 4] {
+        match self {
+            Self::Alder | Self::Birch | Self::Olive => [1.0, 15.0, 90.0, 1500.0],
+            Self::Grass => [1.0, 5.0, 20.0, 200.0],
+            Self::Mugwort | Self::Ragweed => [1.0, 10.0, 50.0, 500.0],
+        }
+    }
+
+    /// Level of a concentration in grains/m³
+    #[must_use]
+    pub fn level(self, concentration: f64) -> PollenLevel {
+        let [low, moderate, high, very_high] = self.thresholds();
+        match concentration {
+            c if c >= very_high => PollenLevel::VeryHigh,
+            c if c >= high => PollenLevel::High,
+            c if c >= moderate => PollenLevel::Moderate,
+            c if c >= low => PollenLevel::Low,
+            _ => PollenLevel::None,
+        }
+    }
+}
+
+/// Highest hourly concentration of a day
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollenPeak {
+    pub datetime: chrono::NaiveDateTime,
+    /// Concentration in grains/m³
+    pub concentration: f64,
+    pub level: PollenLevel,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PollenDay {
+    pub date: chrono::NaiveDate,
+    /// Peak of every species with at least one value
+    pub peaks: BTreeMap<PollenSpecies, PollenPeak>,
+}
+
+impl PollenDay {
+    /// Highest level across species
+    #[must_use]
+    pub fn level(&self) -> PollenLevel {
+        self.peaks
+            .values()
+            .map(|peak| peak.level)
+            .max()
+            .unwrap_or(PollenLevel::None)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PollenAlert {
+    pub date: chrono::NaiveDate,
+    pub species: PollenSpecies,
+    pub peak: PollenPeak,
+}
+
+/// Daily peak of every species, in the local dates of the records
+#[must_use]
+pub fn daily_peaks(hourly: &[ForecastResultHourly]) -> Vec<PollenDay> {
+    let mut days: BTreeMap<chrono::NaiveDate, PollenDay> = BTreeMap::new();
+
+    for rec in hourly {
+        let date = rec.datetime.date();
+        for species in PollenSpecies::ALL {
+            let Some(concentration) = rec.value_f64(&species.pollutant().to_string()) else {
+                continue;
+            };
+
+            let day = days.entry(date).or_insert_with(|| PollenDay {
+                date,
+                ..Default::default()
+            });
+            let peak = PollenPeak {
+                datetime: rec.datetime,
+                concentration,
+                level: species.level(concentration),
+            };
+            day.peaks
+                .entry(species)
+                .and_modify(|current| {
+                    if concentration > current.concentration {
+                        *current = peak;
+                    }
+                })
+                .or_insert(peak);
+        }
+    }
+
+    days.into_values().collect()
+}
+
+/// Daily peaks reaching `min_level`, ordered by date then species
+#[must_use]
+pub fn alerts(hourly: &[ForecastResultHourly], min_level: PollenLevel) -> Vec<PollenAlert> {
+    daily_peaks(hourly)
+        .into_iter()
+        .flat_map(|day| {
+            day.peaks
+                .into_iter()
+                .filter(|(_, peak)| peak.level >= min_level)
+                .map(move |(species, peak)| PollenAlert {
+                    date: day.date,
+                    species,
+                    peak,
+                })
+        })
+        .collect()
+}
+
+impl AirQualityResult {
+    /// Daily peak of every pollen species of the hourly records
+    #[must_use]
+    pub fn pollen_peaks(&self) -> Vec<PollenDay> {
+        daily_peaks(self.hourly.as_deref().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    #[test]
+    fn daily_peaks_and_alerts() {
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 4, 1)
+            .unwrap()
+            .and_time(chrono::NaiveTime::MIN);
+        let hourly: Vec<ForecastResultHourly> = (0..48)
+            .map(|h| ForecastResultHourly {
+                datetime: start + chrono::Duration::hours(h),
+                values: [
+                    ("birch_pollen", if h == 30 { 120.0 } else { 8.0 }),
+                    ("grass_pollen", f64::from(i32::try_from(h % 24).unwrap())),
+                ]
+                .into_iter()
+                .map(|(k, v)| {
+                    (
+                        k.to_string(),
+                        ForecastResultItem {
+                            unit: Some("grains/m³".into()),
+                            value: v.into(),
+                        },
+                    )
+                })
+                .collect(),
+            })
+            .collect();
+
+        let days = daily_peaks(&hourly);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].peaks[&PollenSpecies::Birch].level, PollenLevel::Low);
+        assert_eq!(
+            days[0]
+                .peaks
+                .get(&PollenSpecies::Grass)
+                .map(|p| p.concentration),
+            Some(23.0)
+        );
+        assert_eq!(days[0].level(), PollenLevel::High);
+        assert_eq!(
+            days[1].peaks[&PollenSpecies::Birch].datetime,
+            start + chrono::Duration::hours(30)
+        );
+
+        let alerts = alerts(&hourly, PollenLevel::High);
+        assert_eq!(alerts.len(), 3);
+        assert_eq!(alerts[1].species, PollenSpecies::Birch);
+        assert_eq!(alerts[1].peak.level, PollenLevel::High);
+
+        assert_eq!(PollenSpecies::Ragweed.level(0.5), PollenLevel::None);
+        assert_eq!(PollenSpecies::Olive.level(1500.0), PollenLevel::VeryHigh);
+    }
+}
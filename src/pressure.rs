@@ -0,0 +1,313 @@
+//! Pressure level (upper-air) variables.
+//!
+//! Pressure level variables are requested as `{variable}_{level}hPa`, for example
+//! `temperature_850hPa`:
+//!
+//! ```
+//! use open_meteo_rs::pressure::{PressureLevel, PressureVariable};
+//!
+//! let mut opts = open_meteo_rs::forecast::Options::default();
+//! opts.hourly.extend(open_meteo_rs::pressure::variables(
+//!     &[PressureVariable::Temperature, PressureVariable::WindSpeed],
+//!     &[PressureLevel::Hpa850, PressureLevel::Hpa500],
+//! ));
+//! ```
+
+use crate::errors;
+use crate::forecast::ForecastResultHourly;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Display;
+
+/// Pressure levels available on the forecast API, ordered from the surface upwards
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PressureLevel {
+    Hpa1000,
+    Hpa975,
+    Hpa950,
+    Hpa925,
+    Hpa900,
+    Hpa850,
+    Hpa800,
+    Hpa700,
+    Hpa600,
+    Hpa500,
+    Hpa400,
+    Hpa300,
+    Hpa250,
+    Hpa200,
+    Hpa150,
+    Hpa100,
+    Hpa70,
+    Hpa50,
+    Hpa30,
+}
+
+impl PressureLevel {
+    pub const ALL: [PressureLevel; 19] = [
+        Self::Hpa1000,
+        Self::Hpa975,
+        Self::Hpa950,
+        Self::Hpa925,
+        Self::Hpa900,
+        Self::Hpa850,
+        Self::Hpa800,
+        Self::Hpa700,
+        Self::Hpa600,
+        Self::Hpa500,
+        Self::Hpa400,
+        Self::Hpa300,
+        Self::Hpa250,
+        Self::Hpa200,
+        Self::Hpa150,
+        Self::Hpa100,
+        Self::Hpa70,
+        Self::Hpa50,
+        Self::Hpa30,
+    ];
+
+    /// Pressure in hPa
+    #[must_use]
+    pub fn hpa(&self) -> u16 {
+        match self {
+            Self::Hpa1000 => 1000,
+            Self::Hpa975 => 975,
+            Self::Hpa950 => 950,
+            Self::Hpa925 => 925,
+            Self::Hpa900 => 900,
+            Self::Hpa850 => 850,
+            Self::Hpa800 => 800,
+            Self::Hpa700 => 700,
+            Self::Hpa600 => 600,
+            Self::Hpa500 => 500,
+            Self::Hpa400 => 400,
+            Self::Hpa300 => 300,
+            Self::Hpa250 => 250,
+            Self::Hpa200 => 200,
+            Self::Hpa150 => 150,
+            Self::Hpa100 => 100,
+            Self::Hpa70 => 70,
+            Self::Hpa50 => 50,
+            Self::Hpa30 => 30,
+        }
+    }
+}
+
+impl Display for PressureLevel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}hPa", self.hpa())
+    }
+}
+
+impl From<PressureLevel> for String {
+    fn from(value: PressureLevel) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<u16> for PressureLevel {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|level| level.hpa() == value)
+            .ok_or_else(|| errors::ConversionError::InvalidPressureLevel {
+                level: value.to_string(),
+            })
+    }
+}
+
+impl TryFrom<&str> for PressureLevel {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value
+            .strip_suffix("hPa")
+            .and_then(|v| v.parse::<u16>().ok())
+            .and_then(|v| Self::try_from(v).ok())
+            .ok_or_else(|| errors::ConversionError::InvalidPressureLevel {
+                level: value.to_string(),
+            })
+    }
+}
+
+/// Variables available on pressure levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PressureVariable {
+    Temperature,
+    RelativeHumidity,
+    DewPoint,
+    CloudCover,
+    WindSpeed,
+    WindDirection,
+    GeopotentialHeight,
+    VerticalVelocity,
+}
+
+impl PressureVariable {
+    /// Name of the variable at the given level, as expected by `Options::hourly`
+    #[must_use]
+    pub fn at(&self, level: PressureLevel) -> String {
+        format!("{self}_{level}")
+    }
+}
+
+impl Display for PressureVariable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Temperature => write!(f, "temperature"),
+            Self::RelativeHumidity => write!(f, "relative_humidity"),
+            Self::DewPoint => write!(f, "dew_point"),
+            Self::CloudCover => write!(f, "cloud_cover"),
+            Self::WindSpeed => write!(f, "wind_speed"),
+            Self::WindDirection => write!(f, "wind_direction"),
+            Self::GeopotentialHeight => write!(f, "geopotential_height"),
+            Self::VerticalVelocity => write!(f, "vertical_velocity"),
+        }
+    }
+}
+
+impl From<PressureVariable> for String {
+    fn from(value: PressureVariable) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for PressureVariable {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "temperature" => Ok(Self::Temperature),
+            "relative_humidity" | "relativehumidity" => Ok(Self::RelativeHumidity),
+            "dew_point" | "dewpoint" => Ok(Self::DewPoint),
+            "cloud_cover" | "cloudcover" => Ok(Self::CloudCover),
+            "wind_speed" | "windspeed" => Ok(Self::WindSpeed),
+            "wind_direction" | "winddirection" => Ok(Self::WindDirection),
+            "geopotential_height" => Ok(Self::GeopotentialHeight),
+            "vertical_velocity" => Ok(Self::VerticalVelocity),
+            _ => Err(errors::ConversionError::InvalidPressureVariable {
+                variable: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// Build the hourly variable names for every variable at every level
+#[must_use]
+pub fn variables(variables: &[PressureVariable], levels: &[PressureLevel]) -> Vec<String> {
+    variables
+        .iter()
+        .flat_map(|variable| levels.iter().map(|level| variable.at(*level)))
+        .collect()
+}
+
+/// Split a result key such as `temperature_850hPa` into its variable and level
+#[must_use]
+pub fn parse_key(key: &str) -> Option<(PressureVariable, PressureLevel)> {
+    let (variable, level) = key.rsplit_once('_')?;
+    Some((variable.try_into().ok()?, level.try_into().ok()?))
+}
+
+/// Vertical profile of a variable, from the surface upwards
+pub type Profile = BTreeMap<PressureLevel, f64>;
+
+/// All pressure level profiles available at a given time
+#[derive(Debug, Clone, Default)]
+pub struct Sounding {
+    pub datetime: chrono::NaiveDateTime,
+    pub profiles: HashMap<PressureVariable, Profile>,
+}
+
+impl Sounding {
+    #[must_use]
+    pub fn profile(&self, variable: PressureVariable) -> Option<&Profile> {
+        self.profiles.get(&variable)
+    }
+}
+
+impl ForecastResultHourly {
+    /// Vertical profile of `variable`, levels without a numeric value are skipped
+    #[must_use]
+    pub fn profile(&self, variable: PressureVariable) -> Profile {
+        self.values
+            .iter()
+            .filter_map(|(k, item)| match parse_key(k) {
+                Some((v, level)) if v == variable => Some((level, item.as_f64()?)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Every pressure level variable of the record as a sounding
+    #[must_use]
+    pub fn sounding(&self) -> Sounding {
+        let mut sounding = Sounding {
+            datetime: self.datetime,
+            ..Default::default()
+        };
+
+        for (k, item) in &self.values {
+            if let (Some((variable, level)), Some(value)) = (parse_key(k), item.as_f64()) {
+                sounding
+                    .profiles
+                    .entry(variable)
+                    .or_default()
+                    .insert(level, value);
+            }
+        }
+
+        sounding
+    }
+}
+
+/// Convert a series of hourly records into soundings
+#[must_use]
+pub fn soundings(records: &[ForecastResultHourly]) -> Vec<Sounding> {
+    records.iter().map(ForecastResultHourly::sounding).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    #[test]
+    fn parse_pressure_keys() {
+        assert_eq!(
+            parse_key("geopotential_height_500hPa"),
+            Some((PressureVariable::GeopotentialHeight, PressureLevel::Hpa500))
+        );
+        assert_eq!(parse_key("temperature_2m"), None);
+        assert_eq!(
+            PressureVariable::WindSpeed.at(PressureLevel::Hpa250),
+            "wind_speed_250hPa"
+        );
+    }
+
+    #[test]
+    fn build_profile() {
+        let mut rec = ForecastResultHourly::default();
+        for (k, v) in [
+            ("temperature_500hPa", -20.5),
+            ("temperature_850hPa", 5.0),
+            ("wind_speed_850hPa", 30.0),
+            ("temperature_2m", 12.0),
+        ] {
+            rec.values.insert(
+                k.into(),
+                ForecastResultItem {
+                    unit: None,
+                    value: v.into(),
+                },
+            );
+        }
+
+        let profile = rec.profile(PressureVariable::Temperature);
+        assert_eq!(
+            profile.into_iter().collect::<Vec<_>>(),
+            vec![(PressureLevel::Hpa850, 5.0), (PressureLevel::Hpa500, -20.5)]
+        );
+        assert_eq!(rec.sounding().profiles.len(), 2);
+    }
+}
@@ -0,0 +1,57 @@
+use crate::errors::ConversionError;
+use std::str::FromStr;
+
+/// Split an URL or a query string into decoded key/value pairs
+pub(crate) fn parse_pairs(input: &str) -> Result<Vec<(String, String)>, ConversionError> {
+    let url = if input.contains("://") {
+        reqwest::Url::parse(input)
+    } else {
+        reqwest::Url::parse(&format!(
+            "http://localhost/?{}",
+            input.trim_start_matches('?')
+        ))
+    }
+    .map_err(|_| ConversionError::InvalidQueryString {
+        query: input.to_string(),
+    })?;
+
+    Ok(url.query_pairs().into_owned().collect())
+}
+
+pub(crate) fn parse_value<T: FromStr>(name: &str, value: &str) -> Result<T, ConversionError> {
+    value
+        .parse()
+        .map_err(|_| ConversionError::InvalidParameter {
+            name: name.to_string(),
+            value: value.to_string(),
+        })
+}
+
+pub(crate) fn parse_date(name: &str, value: &str) -> Result<chrono::NaiveDate, ConversionError> {
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d").map_err(|_| {
+        ConversionError::InvalidParameter {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    })
+}
+
+pub(crate) fn parse_datetime(
+    name: &str,
+    value: &str,
+) -> Result<chrono::NaiveDateTime, ConversionError> {
+    chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M").map_err(|_| {
+        ConversionError::InvalidParameter {
+            name: name.to_string(),
+            value: value.to_string(),
+        }
+    })
+}
+
+pub(crate) fn parse_list(value: &str) -> Vec<String> {
+    value
+        .split(',')
+        .filter(|v| !v.is_empty())
+        .map(ToString::to_string)
+        .collect()
+}
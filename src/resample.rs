@@ -0,0 +1,407 @@
+//! Temporal aggregation of hourly and `minutely_15` records.
+//!
+//! ```
+//! use open_meteo_rs::resample::{Aggregation, Interval, Resampler};
+//!
+//! let records: Vec<open_meteo_rs::forecast::ForecastResultHourly> = Vec::new();
+//!
+//! // 3-hourly rows, gusts are aggregated with their maximum by default
+//! let three_hourly = Resampler::new(Interval::Hours(3)).resample(&records);
+//!
+//! // Daily rows in another time zone, records were returned with a UTC offset of 0
+//! let daily = Resampler::new(Interval::Days(1))
+//!     .with_time_zone(0, chrono_tz::America::New_York)
+//!     .with_rule("temperature_2m", Aggregation::Max)
+//!     .resample_daily(&records);
+//! ```
+
+use crate::forecast::{ForecastResultDaily, ForecastResultHourly, ForecastResultItem};
+use crate::series::Series;
+use crate::weather_code::{self, WeatherCode};
+use chrono::{Datelike, NaiveDateTime, TimeZone};
+use std::collections::{BTreeMap, HashMap};
+
+/// How values of a bucket are combined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Min,
+    Max,
+    /// Mean of angles in degrees, for wind directions
+    CircularMean,
+    First,
+    Last,
+    /// Most severe WMO weather code, see [`WeatherCode`]
+    MostSevere,
+}
+
+/// Variables accumulated over time, matched by their exact name
+const SUMMED_VARIABLES: [&str; 7] = [
+    "precipitation",
+    "rain",
+    "showers",
+    "snowfall",
+    "snowfall_water_equivalent",
+    "evapotranspiration",
+    "et0_fao_evapotranspiration",
+];
+
+impl Aggregation {
+    /// Default aggregation for an Open-Meteo variable name
+    #[must_use]
+    pub fn for_variable(name: &str) -> Self {
+        if weather_code::WEATHER_CODE_VARIABLES.contains(&name) {
+            Self::MostSevere
+        } else if name.contains("direction") {
+            Self::CircularMean
+        } else if name.ends_with("_min") {
+            Self::Min
+        } else if name.ends_with("_max") || name.ends_with("_probability") || name.contains("gusts")
+        {
+            Self::Max
+        } else if SUMMED_VARIABLES.contains(&name)
+            || name.ends_with("_sum")
+            || name.ends_with("_duration")
+            || name.ends_with("_hours")
+        {
+            Self::Sum
+        } else {
+            Self::Mean
+        }
+    }
+
+    /// Combine `values`, `None` if there is no value
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn apply(self, values: &[f64]) -> Option<f64> {
+        if values.is_empty() {
+            return None;
+        }
+
+        let count = values.len() as f64;
+        match self {
+            Self::Sum => Some(values.iter().sum()),
+            Self::Mean => Some(values.iter().sum::<f64>() / count),
+            Self::Min => values.iter().copied().reduce(f64::min),
+            Self::Max => values.iter().copied().reduce(f64::max),
+            Self::CircularMean => {
+                let (sin, cos) = values.iter().fold((0.0, 0.0), |(s, c), v| {
+                    (s + v.to_radians().sin(), c + v.to_radians().cos())
+                });
+                Some(sin.atan2(cos).to_degrees().rem_euclid(360.0))
+            }
+            Self::First => values.first().copied(),
+            Self::Last => values.last().copied(),
+            Self::MostSevere => values
+                .iter()
+                .filter_map(|v| WeatherCode::try_from(&serde_json::Value::from(*v)).ok())
+                .max()
+                .map(|code| f64::from(code.code())),
+        }
+    }
+}
+
+/// Bucket size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    Hours(u32),
+    Days(u32),
+    /// ISO weeks, starting on monday
+    Week,
+    Month,
+}
+
+impl Interval {
+    /// Start of the bucket containing `datetime`
+    fn bucket(self, datetime: NaiveDateTime) -> NaiveDateTime {
+        let date = datetime.date();
+        match self {
+            Self::Hours(n) => floor(datetime, i64::from(n.max(1)) * 3_600),
+            Self::Days(n) => floor(datetime, i64::from(n.max(1)) * 86_400),
+            Self::Week => {
+                let days = i64::from(date.weekday().num_days_from_monday());
+                (date - chrono::Duration::days(days)).and_time(chrono::NaiveTime::MIN)
+            }
+            Self::Month => date
+                .with_day(1)
+                .unwrap_or(date)
+                .and_time(chrono::NaiveTime::MIN),
+        }
+    }
+}
+
+fn floor(datetime: NaiveDateTime, seconds: i64) -> NaiveDateTime {
+    let ts = datetime.and_utc().timestamp();
+    chrono::DateTime::from_timestamp(ts - ts.rem_euclid(seconds), 0)
+        .map_or(datetime, |d| d.naive_utc())
+}
+
+/// Unit and values of a variable within a bucket
+type Bucket<'a> = (Option<&'a str>, Vec<f64>);
+
+/// Period used to group records across years
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    /// ISO week number, 1 to 53
+    WeekOfYear,
+    /// Month, 1 to 12
+    MonthOfYear,
+}
+
+/// Aggregated values of a climatological period
+#[derive(Debug, Default)]
+pub struct ClimatologyRow {
+    pub period: u32,
+    pub values: HashMap<String, ForecastResultItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Resampler {
+    interval: Interval,
+    rules: HashMap<String, Aggregation>,
+    time_zone: Option<(i32, chrono_tz::Tz)>,
+}
+
+impl Resampler {
+    #[must_use]
+    pub fn new(interval: Interval) -> Self {
+        Self {
+            interval,
+            rules: HashMap::new(),
+            time_zone: None,
+        }
+    }
+
+    /// Override the aggregation of a variable
+    #[must_use]
+    pub fn with_rule(mut self, name: impl Into<String>, aggregation: Aggregation) -> Self {
+        self.rules.insert(name.into(), aggregation);
+        self
+    }
+
+    /// Bucket records in `time_zone`. `utc_offset_seconds` is the offset of the records, as
+    /// returned in the response metadata.
+    #[must_use]
+    pub fn with_time_zone(mut self, utc_offset_seconds: i32, time_zone: chrono_tz::Tz) -> Self {
+        self.time_zone = Some((utc_offset_seconds, time_zone));
+        self
+    }
+
+    /// Resample into rows starting at each bucket
+    #[must_use]
+    pub fn resample(&self, records: &[ForecastResultHourly]) -> Vec<ForecastResultHourly> {
+        self.group(records, |datetime| self.interval.bucket(datetime))
+            .into_iter()
+            .map(|(datetime, values)| ForecastResultHourly { datetime, values })
+            .collect()
+    }
+
+    /// Resample into daily rows, the interval is ignored
+    #[must_use]
+    pub fn resample_daily(&self, records: &[ForecastResultHourly]) -> Vec<ForecastResultDaily> {
+        self.group(records, |datetime| datetime.date())
+            .into_iter()
+            .map(|(date, values)| ForecastResultDaily { date, values })
+            .collect()
+    }
+
+    /// Aggregate records of every year by week or month, the interval is ignored
+    #[must_use]
+    pub fn climatology(
+        &self,
+        records: &[ForecastResultHourly],
+        period: Period,
+    ) -> Vec<ClimatologyRow> {
+        self.group(records, |datetime| match period {
+            Period::WeekOfYear => datetime.iso_week().week(),
+            Period::MonthOfYear => datetime.month(),
+        })
+        .into_iter()
+        .map(|(period, values)| ClimatologyRow { period, values })
+        .collect()
+    }
+
+    /// Aggregate `series` over a trailing window of `window` records, using the aggregation of
+    /// its name
+    #[must_use]
+    pub fn rolling(&self, series: &Series, window: usize) -> Series {
+        let aggregation = self.aggregation(&series.name);
+        let window = window.max(1);
+
+        let values = (0..series.values.len())
+            .map(|idx| {
+                let start = (idx + 1).saturating_sub(window);
+                let values: Vec<f64> = series.values[start..=idx]
+                    .iter()
+                    .flatten()
+                    .copied()
+                    .collect();
+                aggregation.apply(&values)
+            })
+            .collect();
+
+        Series::new(series.name.clone(), series.unit.clone(), values)
+    }
+
+    fn aggregation(&self, name: &str) -> Aggregation {
+        self.rules
+            .get(name)
+            .copied()
+            .unwrap_or_else(|| Aggregation::for_variable(name))
+    }
+
+    fn local_datetime(&self, datetime: NaiveDateTime) -> NaiveDateTime {
+        match self.time_zone {
+            Some((offset, tz)) => {
+                let utc = datetime - chrono::Duration::seconds(i64::from(offset));
+                tz.from_utc_datetime(&utc).naive_local()
+            }
+            None => datetime,
+        }
+    }
+
+    fn group<K: Ord + Copy>(
+        &self,
+        records: &[ForecastResultHourly],
+        key: impl Fn(NaiveDateTime) -> K,
+    ) -> Vec<(K, HashMap<String, ForecastResultItem>)> {
+        let mut buckets: BTreeMap<K, HashMap<&str, Bucket>> = BTreeMap::new();
+
+        for rec in records {
+            let bucket = buckets
+                .entry(key(self.local_datetime(rec.datetime)))
+                .or_default();
+
+            for (name, item) in &rec.values {
+                let entry = bucket.entry(name).or_insert((None, Vec::new()));
+                if entry.0.is_none() {
+                    entry.0 = item.unit.as_deref();
+                }
+                if let Some(v) = item.as_f64() {
+                    entry.1.push(v);
+                }
+            }
+        }
+
+        buckets
+            .into_iter()
+            .map(|(k, variables)| {
+                let values = variables
+                    .into_iter()
+                    .map(|(name, (unit, values))| {
+                        let value = self.aggregation(name).apply(&values);
+                        (
+                            name.to_string(),
+                            ForecastResultItem {
+                                unit: unit.map(ToString::to_string),
+                                value: value.map_or(serde_json::Value::Null, Into::into),
+                            },
+                        )
+                    })
+                    .collect();
+                (k, values)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn records() -> Vec<ForecastResultHourly> {
+        let start = NaiveDate::from_ymd_opt(2024, 1, 1)
+            .unwrap()
+            .and_time(chrono::NaiveTime::MIN);
+
+        (0..48)
+            .map(|h| {
+                let mut rec = ForecastResultHourly {
+                    datetime: start + chrono::Duration::hours(h),
+                    ..Default::default()
+                };
+                for (k, v) in [
+                    ("precipitation", 1.0),
+                    ("temperature_2m", f64::from(i32::try_from(h % 24).unwrap())),
+                    ("wind_direction_10m", if h % 2 == 0 { 350.0 } else { 10.0 }),
+                ] {
+                    rec.values.insert(
+                        k.into(),
+                        ForecastResultItem {
+                            unit: None,
+                            value: v.into(),
+                        },
+                    );
+                }
+                rec
+            })
+            .collect()
+    }
+
+    #[test]
+    fn resample_with_default_rules() {
+        let daily = Resampler::new(Interval::Days(1)).resample_daily(&records());
+        assert_eq!(daily.len(), 2);
+        assert_eq!(daily[0].value_f64("precipitation"), Some(24.0));
+        assert_eq!(daily[0].value_f64("temperature_2m"), Some(11.5));
+        let direction = daily[0].value_f64("wind_direction_10m").unwrap();
+        assert!(direction < 1e-6 || (360.0 - direction) < 1e-6);
+
+        let three_hourly = Resampler::new(Interval::Hours(3))
+            .with_rule("temperature_2m", Aggregation::Max)
+            .resample(&records());
+        assert_eq!(three_hourly.len(), 16);
+        assert_eq!(three_hourly[0].value_f64("temperature_2m"), Some(2.0));
+    }
+
+    #[test]
+    fn resample_in_another_time_zone() {
+        let daily = Resampler::new(Interval::Days(1))
+            .with_time_zone(0, chrono_tz::Asia::Tokyo)
+            .resample_daily(&records());
+        assert_eq!(daily.len(), 3);
+        assert_eq!(daily[0].value_f64("precipitation"), Some(15.0));
+
+        let series = Series::new("precipitation", None, vec![Some(1.0); 4]);
+        let rolling = Resampler::new(Interval::Days(1)).rolling(&series, 3);
+        assert_eq!(
+            rolling.values,
+            vec![Some(1.0), Some(2.0), Some(3.0), Some(3.0)]
+        );
+    }
+
+    #[test]
+    fn default_aggregations() {
+        for (name, aggregation) in [
+            ("precipitation", Aggregation::Sum),
+            ("precipitation_sum", Aggregation::Sum),
+            ("precipitation_hours", Aggregation::Sum),
+            ("sunshine_duration", Aggregation::Sum),
+            ("precipitation_probability", Aggregation::Max),
+            ("precipitation_probability_max", Aggregation::Max),
+            ("precipitation_probability_min", Aggregation::Min),
+            ("precipitation_probability_mean", Aggregation::Mean),
+            ("wind_gusts_10m", Aggregation::Max),
+            ("wind_direction_10m", Aggregation::CircularMean),
+            ("temperature_2m_min", Aggregation::Min),
+            ("snow_depth", Aggregation::Mean),
+            ("weather_code", Aggregation::MostSevere),
+        ] {
+            assert_eq!(Aggregation::for_variable(name), aggregation, "{name}");
+        }
+    }
+
+    #[test]
+    fn weather_code_keeps_most_severe() {
+        // Heavy rain (65) is more severe than slight snow showers (85)
+        assert_eq!(
+            Aggregation::MostSevere.apply(&[45.0, 65.0, 85.0, 3.0]),
+            Some(65.0)
+        );
+        // Invalid codes are ignored
+        assert_eq!(Aggregation::MostSevere.apply(&[4.0, 2.0]), Some(2.0));
+        assert_eq!(Aggregation::MostSevere.apply(&[4.0]), None);
+    }
+}
@@ -0,0 +1,258 @@
+//! Offline reverse geocoding from a `GeoNames` cities dataset, such as `cities1000.txt` or
+//! `cities15000.txt` from <https://download.geonames.org/export/dump/>.
+//!
+//! ```no_run
+//! use open_meteo_rs::reverse_geocoding::ReverseGeocoder;
+//! use open_meteo_rs::Location;
+//!
+//! let geocoder = ReverseGeocoder::from_path("cities15000.txt").unwrap();
+//! let places = geocoder.nearest(&Location { lat: 48.85, lng: 2.35 }, 3);
+//! ```
+
+use super::{errors, geocoding, location};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::io::BufRead;
+use std::path::Path;
+
+/// Index of places, queried by distance
+#[derive(Debug, Clone, Default)]
+pub struct ReverseGeocoder {
+    places: Vec<geocoding::GeocodingResult>,
+    /// Position of each place on the unit sphere, in k-d tree order
+    points: Vec<[f64; 3]>,
+    /// Index in `places` of each point
+    ids: Vec<usize>,
+}
+
+impl ReverseGeocoder {
+    /// Index `places`, places without coordinates are skipped
+    #[must_use]
+    pub fn new(places: Vec<geocoding::GeocodingResult>) -> Self {
+        let mut nodes: Vec<([f64; 3], usize)> = places
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, place)| Some((to_point(&place.location()?), idx)))
+            .collect();
+        build(&mut nodes, 0);
+
+        let (points, ids) = nodes.into_iter().unzip();
+        Self {
+            places,
+            points,
+            ids,
+        }
+    }
+
+    /// Load a `GeoNames` dump file
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the file cannot be read or contains an invalid record.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, Box<dyn Error>> {
+        let file = std::fs::File::open(path)?;
+        Self::from_reader(std::io::BufReader::new(file))
+    }
+
+    /// Load `GeoNames` records, one tab separated record per line
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if the reader fails or a record is invalid.
+    pub fn from_reader(reader: impl BufRead) -> Result<Self, Box<dyn Error>> {
+        let mut places = Vec::new();
+        for (idx, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            places.push(
+                parse_record(&line)
+                    .ok_or(errors::ConversionError::InvalidGeoNamesRecord { line: idx + 1 })?,
+            );
+        }
+        Ok(Self::new(places))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// The `k` places nearest to `location`, closest first
+    #[must_use]
+    pub fn nearest(
+        &self,
+        location: &location::Location,
+        k: usize,
+    ) -> Vec<geocoding::GeocodingResult> {
+        if k == 0 {
+            return Vec::new();
+        }
+
+        let mut heap = BinaryHeap::with_capacity(k + 1);
+        self.search(&to_point(location), k, 0, self.points.len(), 0, &mut heap);
+
+        heap.into_sorted_vec()
+            .into_iter()
+            .map(|candidate| self.places[self.ids[candidate.node]].clone())
+            .collect()
+    }
+
+    fn search(
+        &self,
+        target: &[f64; 3],
+        k: usize,
+        start: usize,
+        end: usize,
+        axis: usize,
+        heap: &mut BinaryHeap<Candidate>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let mid = start + (end - start) / 2;
+        let point = &self.points[mid];
+        heap.push(Candidate {
+            distance: distance(point, target),
+            node: mid,
+        });
+        if heap.len() > k {
+            heap.pop();
+        }
+
+        let diff = target[axis] - point[axis];
+        let (near, far) = if diff < 0.0 {
+            ((start, mid), (mid + 1, end))
+        } else {
+            ((mid + 1, end), (start, mid))
+        };
+        let next = (axis + 1) % 3;
+
+        self.search(target, k, near.0, near.1, next, heap);
+        if heap.len() < k || heap.peek().is_some_and(|c| diff * diff < c.distance) {
+            self.search(target, k, far.0, far.1, next, heap);
+        }
+    }
+}
+
+/// Node of the tree with its squared distance to the target, ordered by distance
+#[derive(Debug, PartialEq)]
+struct Candidate {
+    distance: f64,
+    node: usize,
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance.total_cmp(&other.distance)
+    }
+}
+
+/// Sort `nodes` in place so that every median is the root of its sub-slice
+fn build(nodes: &mut [([f64; 3], usize)], axis: usize) {
+    if nodes.len() <= 1 {
+        return;
+    }
+
+    let mid = nodes.len() / 2;
+    nodes.select_nth_unstable_by(mid, |a, b| a.0[axis].total_cmp(&b.0[axis]));
+    let (left, right) = nodes.split_at_mut(mid);
+    build(left, (axis + 1) % 3);
+    build(&mut right[1..], (axis + 1) % 3);
+}
+
+fn to_point(location: &location::Location) -> [f64; 3] {
+    let (lat, lng) = (location.lat.to_radians(), location.lng.to_radians());
+    [lat.cos() * lng.cos(), lat.cos() * lng.sin(), lat.sin()]
+}
+
+/// Squared chord distance, ordered like the great circle distance
+fn distance(a: &[f64; 3], b: &[f64; 3]) -> f64 {
+    a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum()
+}
+
+/// Parse a `GeoNames` record, see <https://download.geonames.org/export/dump/readme.txt>
+fn parse_record(line: &str) -> Option<geocoding::GeocodingResult> {
+    let fields: Vec<&str> = line.split('\t').collect();
+    if fields.len() < 18 {
+        return None;
+    }
+
+    let optional = |idx: usize| Some(fields[idx]).filter(|v| !v.is_empty());
+    let elevation = optional(15).or(optional(16)).and_then(|v| v.parse().ok());
+
+    Some(geocoding::GeocodingResult {
+        id: Some(fields[0].parse().ok()?),
+        name: Some(fields[1].to_string()),
+        latitude: Some(fields[4].parse().ok()?),
+        longitude: Some(fields[5].parse().ok()?),
+        elevation,
+        feature_code: optional(7).and_then(|v| geocoding::FeatureCode::try_from(v).ok()),
+        country_code: optional(8).map(ToString::to_string),
+        population: optional(14).and_then(|v| v.parse().ok()),
+        timezone: optional(17).map(ToString::to_string),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CITIES: &str = "\
+2988507\tParis\tParis\t\t48.85341\t2.3488\tP\tPPLC\tFR\t\t11\t75\t751\t75056\t2138551\t\t42\tEurope/Paris\t2024-01-01
+2950159\tBerlin\tBerlin\t\t52.52437\t13.41053\tP\tPPLC\tDE\t\t16\t00\t11000\t11000000\t3426354\t\t74\tEurope/Berlin\t2024-01-01
+2643743\tLondon\tLondon\t\t51.50853\t-0.12574\tP\tPPLC\tGB\t\tENG\tGLA\t\t\t8961989\t\t25\tEurope/London\t2024-01-01
+5128581\tNew York City\tNew York City\t\t40.71427\t-74.00597\tP\tPPL\tUS\t\tNY\t\t\t\t8804190\t10\t57\tAmerica/New_York\t2024-01-01
+1850147\tTokyo\tTokyo\t\t35.6895\t139.69171\tP\tPPLC\tJP\t\t40\t\t\t\t8336599\t\t44\tAsia/Tokyo\t2024-01-01
+";
+
+    #[test]
+    fn nearest_places() {
+        let geocoder = ReverseGeocoder::from_reader(CITIES.as_bytes()).unwrap();
+        assert_eq!(geocoder.len(), 5);
+
+        let places = geocoder.nearest(
+            &location::Location {
+                lat: 50.0,
+                lng: 1.0,
+            },
+            2,
+        );
+        let names: Vec<_> = places.iter().filter_map(|p| p.name.as_deref()).collect();
+        assert_eq!(names, ["Paris", "London"]);
+        assert_eq!(places[0].feature_code, Some(geocoding::FeatureCode::Pplc));
+        assert_eq!(places[0].elevation, Some(42.0));
+
+        let places = geocoder.nearest(
+            &location::Location {
+                lat: 35.0,
+                lng: -179.0,
+            },
+            1,
+        );
+        assert_eq!(places[0].name.as_deref(), Some("Tokyo"));
+        assert_eq!(
+            geocoder.nearest(&location::Location::default(), 10).len(),
+            5
+        );
+
+        assert!(ReverseGeocoder::from_reader("1\tbroken".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,101 @@
+//! Named numeric series extracted from, or merged into, result records.
+
+use crate::forecast::{ForecastResultDaily, ForecastResultHourly, ForecastResultItem};
+use crate::units::Unit;
+
+/// A variable over time, one value per record
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Series {
+    pub name: String,
+    pub unit: Option<String>,
+    pub values: Vec<Option<f64>>,
+}
+
+impl Series {
+    #[must_use]
+    pub fn new(name: impl Into<String>, unit: Option<String>, values: Vec<Option<f64>>) -> Self {
+        Self {
+            name: name.into(),
+            unit,
+            values,
+        }
+    }
+
+    /// Extract the variable `name`, `None` if no record contains it
+    #[must_use]
+    pub fn from_hourly(records: &[ForecastResultHourly], name: &str) -> Option<Self> {
+        let unit = records
+            .iter()
+            .find_map(|rec| rec.values.get(name))?
+            .unit
+            .clone();
+
+        Some(Self {
+            name: name.to_string(),
+            unit,
+            values: records.iter().map(|rec| rec.value_f64(name)).collect(),
+        })
+    }
+
+    /// Extract the variable `name` converted to `unit`. `None` if no record contains it or if
+    /// its unit cannot be converted.
+    #[must_use]
+    pub fn from_hourly_in(
+        records: &[ForecastResultHourly],
+        name: &str,
+        unit: Unit,
+    ) -> Option<Self> {
+        Self::from_hourly(records, name)?.converted(unit)
+    }
+
+    /// Extract the daily variable `name`, `None` if no record contains it
+    #[must_use]
+    pub fn from_daily(records: &[ForecastResultDaily], name: &str) -> Option<Self> {
+        let unit = records
+            .iter()
+            .find_map(|rec| rec.values.get(name))?
+            .unit
+            .clone();
+
+        Some(Self {
+            name: name.to_string(),
+            unit,
+            values: records.iter().map(|rec| rec.value_f64(name)).collect(),
+        })
+    }
+
+    /// Extract the daily variable `name` converted to `unit`. `None` if no record contains it
+    /// or if its unit cannot be converted.
+    #[must_use]
+    pub fn from_daily_in(records: &[ForecastResultDaily], name: &str, unit: Unit) -> Option<Self> {
+        Self::from_daily(records, name)?.converted(unit)
+    }
+
+    fn converted(self, unit: Unit) -> Option<Self> {
+        let from = Unit::try_from(self.unit.as_deref()?).ok()?;
+        from.convert(0.0, unit)?;
+
+        Some(Self {
+            name: self.name,
+            unit: Some(unit.to_string()),
+            values: self
+                .values
+                .into_iter()
+                .map(|v| from.convert(v?, unit))
+                .collect(),
+        })
+    }
+
+    /// Insert the series in the records, matched by position
+    pub fn merge_into(&self, records: &mut [ForecastResultHourly]) {
+        for (rec, value) in records.iter_mut().zip(&self.values) {
+            rec.values.insert(
+                self.name.clone(),
+                ForecastResultItem {
+                    unit: self.unit.clone(),
+                    value: value.map_or(serde_json::Value::Null, Into::into),
+                },
+            );
+        }
+    }
+}
@@ -0,0 +1,292 @@
+//! Local solar geometry and plane-of-array irradiance.
+//!
+//! Azimuths follow the Open-Meteo panel convention: 0° is south, -90° east, 90° west and ±180°
+//! north.
+
+use crate::forecast::{Azimuth, ForecastResultHourly, Tilt};
+use crate::location;
+
+const GHI_VARIABLE: &str = "shortwave_radiation";
+const DNI_VARIABLE: &str = "direct_normal_irradiance";
+const DHI_VARIABLE: &str = "diffuse_radiation";
+
+/// Default ground reflectance used for the reflected component
+pub const DEFAULT_ALBEDO: f64 = 0.2;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SunPosition {
+    /// Angle above the horizon in degrees, negative at night
+    pub elevation: f64,
+    /// Sun azimuth in degrees (0 is south, -90 east, 90 west)
+    pub azimuth: f64,
+}
+
+impl SunPosition {
+    /// Zenith angle in degrees
+    #[must_use]
+    pub fn zenith(&self) -> f64 {
+        90.0 - self.elevation
+    }
+
+    /// Angle of incidence between the sun beam and the normal of a panel, in degrees
+    #[must_use]
+    pub fn angle_of_incidence(&self, tilt: Tilt, azimuth: Azimuth) -> f64 {
+        let zenith = self.zenith().to_radians();
+        let tilt = f64::from(tilt.value()).to_radians();
+        let relative_azimuth = (self.azimuth - f64::from(azimuth.value())).to_radians();
+
+        let cos_aoi =
+            zenith.cos() * tilt.cos() + zenith.sin() * tilt.sin() * relative_azimuth.cos();
+        cos_aoi.clamp(-1.0, 1.0).acos().to_degrees()
+    }
+}
+
+/// Irradiance components on the horizontal plane, in W/m²
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Irradiance {
+    /// Global horizontal irradiance
+    pub ghi: f64,
+    /// Direct normal irradiance
+    pub dni: f64,
+    /// Diffuse horizontal irradiance
+    pub dhi: f64,
+}
+
+/// Compute the sun position for a UTC datetime using the NOAA solar equations.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn sun_position(
+    datetime_utc: chrono::NaiveDateTime,
+    location: &location::Location,
+) -> SunPosition {
+    let timestamp = datetime_utc.and_utc().timestamp() as f64;
+    let julian_day = timestamp / 86_400.0 + 2_440_587.5;
+    let t = (julian_day - 2_451_545.0) / 36_525.0;
+
+    let mean_longitude = (280.466_46 + t * (36_000.769_83 + t * 0.000_303_2)).rem_euclid(360.0);
+    let mean_anomaly = 357.529_11 + t * (35_999.050_29 - 0.000_153_7 * t);
+    let eccentricity = 0.016_708_634 - t * (0.000_042_037 + 0.000_000_126_7 * t);
+
+    let m = mean_anomaly.to_radians();
+    let center = m.sin() * (1.914_602 - t * (0.004_817 + 0.000_014 * t))
+        + (2.0 * m).sin() * (0.019_993 - 0.000_101 * t)
+        + (3.0 * m).sin() * 0.000_289;
+    let omega = (125.04 - 1_934.136 * t).to_radians();
+    let apparent_longitude =
+        (mean_longitude + center - 0.005_69 - 0.004_78 * omega.sin()).to_radians();
+
+    let mean_obliquity =
+        23.0 + (26.0 + (21.448 - t * (46.815 + t * (0.000_59 - t * 0.001_813))) / 60.0) / 60.0;
+    let obliquity = (mean_obliquity + 0.002_56 * omega.cos()).to_radians();
+    let declination = (obliquity.sin() * apparent_longitude.sin()).asin();
+
+    let l0 = mean_longitude.to_radians();
+    let y = (obliquity / 2.0).tan().powi(2);
+    let equation_of_time = 4.0
+        * (y * (2.0 * l0).sin() - 2.0 * eccentricity * m.sin()
+            + 4.0 * eccentricity * y * m.sin() * (2.0 * l0).cos()
+            - 0.5 * y * y * (4.0 * l0).sin()
+            - 1.25 * eccentricity * eccentricity * (2.0 * m).sin())
+        .to_degrees();
+
+    let minutes = timestamp.rem_euclid(86_400.0) / 60.0;
+    let true_solar_time = (minutes + equation_of_time + 4.0 * location.lng).rem_euclid(1_440.0);
+    let hour_angle = (true_solar_time / 4.0 - 180.0).to_radians();
+
+    let lat = location.lat.to_radians();
+    let cos_zenith = (lat.sin() * declination.sin()
+        + lat.cos() * declination.cos() * hour_angle.cos())
+    .clamp(-1.0, 1.0);
+    let elevation = 90.0 - cos_zenith.acos().to_degrees();
+
+    // Azimuth measured from north, clockwise
+    let azimuth_north = hour_angle
+        .sin()
+        .atan2(hour_angle.cos() * lat.sin() - declination.tan() * lat.cos())
+        .to_degrees()
+        + 180.0;
+
+    SunPosition {
+        elevation,
+        azimuth: azimuth_north - 180.0,
+    }
+}
+
+/// Compute the irradiance received by a panel using the isotropic sky model.
+#[must_use]
+pub fn plane_of_array(
+    irradiance: Irradiance,
+    sun: SunPosition,
+    tilt: Tilt,
+    azimuth: Azimuth,
+    albedo: f64,
+) -> f64 {
+    let beta = f64::from(tilt.value()).to_radians();
+
+    let beam = if sun.elevation > 0.0 {
+        irradiance.dni
+            * sun
+                .angle_of_incidence(tilt, azimuth)
+                .to_radians()
+                .cos()
+                .max(0.0)
+    } else {
+        0.0
+    };
+    let sky_diffuse = irradiance.dhi * (1.0 + beta.cos()) / 2.0;
+    let reflected = irradiance.ghi * albedo * (1.0 - beta.cos()) / 2.0;
+
+    (beam + sky_diffuse + reflected).max(0.0)
+}
+
+/// Compute the sun position for every record of a series.
+///
+/// Record datetimes are local to the response, `utc_offset_seconds` is the offset the API
+/// returned. The position is taken at the middle of each interval since Open-Meteo radiation
+/// values are averages over the preceding interval.
+#[must_use]
+pub fn sun_positions(
+    records: &[ForecastResultHourly],
+    location: &location::Location,
+    utc_offset_seconds: i32,
+) -> Vec<SunPosition> {
+    let half_interval = interval(records) / 2;
+    let offset = chrono::Duration::seconds(i64::from(utc_offset_seconds));
+
+    records
+        .iter()
+        .map(|rec| sun_position(rec.datetime - offset - half_interval, location))
+        .collect()
+}
+
+/// Compute the plane-of-array irradiance for every record of a series.
+///
+/// Records must contain `shortwave_radiation`, `direct_normal_irradiance` and
+/// `diffuse_radiation` in W/m², records where one of them is missing yield `None`.
+#[must_use]
+pub fn plane_of_array_series(
+    records: &[ForecastResultHourly],
+    location: &location::Location,
+    utc_offset_seconds: i32,
+    tilt: Tilt,
+    azimuth: Azimuth,
+    albedo: f64,
+) -> Vec<Option<f64>> {
+    sun_positions(records, location, utc_offset_seconds)
+        .into_iter()
+        .zip(records)
+        .map(|(sun, rec)| {
+            let irradiance = Irradiance {
+                ghi: rec.value_f64(GHI_VARIABLE)?,
+                dni: rec.value_f64(DNI_VARIABLE)?,
+                dhi: rec.value_f64(DHI_VARIABLE)?,
+            };
+
+            Some(plane_of_array(irradiance, sun, tilt, azimuth, albedo))
+        })
+        .collect()
+}
+
+/// Interval between two records, one hour if it cannot be determined
+fn interval(records: &[ForecastResultHourly]) -> chrono::Duration {
+    match records {
+        [first, second, ..] if second.datetime > first.datetime => second.datetime - first.datetime,
+        _ => chrono::Duration::hours(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sun_position_at_solar_noon() {
+        // Equinox, close to solar noon in Greenwich
+        let datetime = chrono::NaiveDate::from_ymd_opt(2024, 3, 20)
+            .unwrap()
+            .and_hms_opt(12, 7, 0)
+            .unwrap();
+        let location = location::Location {
+            lat: 51.48,
+            lng: 0.0,
+        };
+        let sun = sun_position(datetime, &location);
+
+        assert!((sun.elevation - (90.0 - 51.48)).abs() < 0.5);
+        assert!(sun.azimuth.abs() < 1.0);
+    }
+
+    #[test]
+    fn plane_of_array_facing_the_sun() {
+        let sun = SunPosition {
+            elevation: 45.0,
+            azimuth: 0.0,
+        };
+        let irradiance = Irradiance {
+            ghi: 600.0,
+            dni: 700.0,
+            dhi: 100.0,
+        };
+        let tilt = Tilt::try_from(45.0).unwrap();
+        let azimuth = Azimuth::try_from(0.0).unwrap();
+
+        let poa = plane_of_array(irradiance, sun, tilt, azimuth, 0.0);
+        let expected = 700.0 + 100.0 * (1.0 + 45_f64.to_radians().cos()) / 2.0;
+        assert!((poa - expected).abs() < 1e-6);
+
+        assert!(Tilt::try_from(91.0).is_err());
+        assert!(Azimuth::try_from(-181.0).is_err());
+    }
+
+    #[test]
+    fn series_uses_mid_interval_and_skips_missing_values() {
+        let location = location::Location {
+            lat: 51.48,
+            lng: 0.0,
+        };
+        let start = chrono::NaiveDate::from_ymd_opt(2024, 3, 20)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap();
+        let records: Vec<ForecastResultHourly> = [
+            [Some(500.0), Some(600.0), Some(100.0)],
+            [Some(500.0), None, Some(100.0)],
+            [Some(500.0), Some(600.0), None],
+        ]
+        .iter()
+        .enumerate()
+        .map(|(hour, values)| ForecastResultHourly {
+            datetime: start + chrono::Duration::hours(i64::try_from(hour).unwrap()),
+            values: [GHI_VARIABLE, DNI_VARIABLE, DHI_VARIABLE]
+                .into_iter()
+                .zip(values)
+                .map(|(name, value)| {
+                    let item = crate::forecast::ForecastResultItem {
+                        unit: Some("W/m²".into()),
+                        value: value.map_or(serde_json::Value::Null, serde_json::Value::from),
+                    };
+                    (name.to_string(), item)
+                })
+                .collect(),
+        })
+        .collect();
+
+        // Local time is UTC+1 and values cover the preceding hour, the 12:00 record is
+        // positioned at 10:30 UTC
+        let suns = sun_positions(&records, &location, 3600);
+        for (hour, sun) in suns.iter().enumerate() {
+            let hour = i64::try_from(hour).unwrap();
+            let mid_interval =
+                start + chrono::Duration::hours(hour) - chrono::Duration::minutes(90);
+            assert_eq!(*sun, sun_position(mid_interval, &location));
+        }
+        assert_ne!(suns[0], sun_position(start, &location));
+
+        let tilt = Tilt::try_from(30.0).unwrap();
+        let azimuth = Azimuth::try_from(0.0).unwrap();
+        let poa = plane_of_array_series(&records, &location, 3600, tilt, azimuth, DEFAULT_ALBEDO);
+        assert!(poa[0].is_some_and(|value| value > 0.0));
+        assert_eq!(poa[1], None);
+        assert_eq!(poa[2], None);
+    }
+}
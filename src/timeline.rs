@@ -0,0 +1,210 @@
+//! Observed history followed by the forecast, as a single hourly series.
+
+use super::{client, forecast, location};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+
+/// API a record was taken from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Source {
+    Archive,
+    Forecast,
+}
+
+/// Source kept when both APIs return the same timestamp
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Precedence {
+    #[default]
+    Archive,
+    Forecast,
+}
+
+#[derive(Debug, Clone)]
+pub struct TimelineRecord {
+    pub source: Source,
+    pub record: forecast::ForecastResultHourly,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Timeline {
+    /// Metadata of the forecast response
+    pub metadata: forecast::Metadata,
+    pub hourly: Vec<TimelineRecord>,
+}
+
+/// Merge archive and forecast records ordered by datetime. On overlapping timestamps the
+/// record of the preferred source is kept, unless all its values are missing.
+#[must_use]
+pub fn merge(
+    archive: Vec<forecast::ForecastResultHourly>,
+    forecast: Vec<forecast::ForecastResultHourly>,
+    precedence: Precedence,
+) -> Vec<TimelineRecord> {
+    merge_by(
+        archive,
+        forecast,
+        precedence,
+        |rec| rec.datetime,
+        |rec| &rec.values,
+    )
+    .into_iter()
+    .map(|(source, record)| TimelineRecord { source, record })
+    .collect()
+}
+
+/// Same as [`merge`] for any record type, keyed and ordered by `key`
+pub(crate) fn merge_by<R, K: Ord>(
+    archive: Vec<R>,
+    forecast: Vec<R>,
+    precedence: Precedence,
+    key: impl Fn(&R) -> K,
+    values: impl Fn(&R) -> &HashMap<String, forecast::ForecastResultItem>,
+) -> Vec<(Source, R)> {
+    let (preferred, other) = match precedence {
+        Precedence::Archive => ((archive, Source::Archive), (forecast, Source::Forecast)),
+        Precedence::Forecast => ((forecast, Source::Forecast), (archive, Source::Archive)),
+    };
+
+    let mut merged = BTreeMap::new();
+    for (records, source) in [other, preferred] {
+        for record in records {
+            let is_empty = values(&record).values().all(|item| item.value.is_null());
+            if is_empty && merged.contains_key(&key(&record)) {
+                continue;
+            }
+            merged.insert(key(&record), (source, record));
+        }
+    }
+
+    merged.into_values().collect()
+}
+
+impl client::Client {
+    /// Hourly `variables` from `past_days` ago until `forecast_days` ahead, in UTC. The archive
+    /// is used for history, the days it lags behind are filled from the forecast API. Archive
+    /// records take precedence on overlapping timestamps.
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if options are invalid, if api return an error or in case of network error.
+    pub async fn timeline(
+        &self,
+        location: location::Location,
+        variables: Vec<String>,
+        past_days: u16,
+        forecast_days: u8,
+    ) -> Result<Timeline, Box<dyn Error>> {
+        self.timeline_with_precedence(
+            location,
+            variables,
+            past_days,
+            forecast_days,
+            Precedence::default(),
+        )
+        .await
+    }
+
+    /// Same as [`Client::timeline`](client::Client::timeline) with a configurable precedence
+    ///
+    /// ### Errors
+    ///
+    /// Return an `Err` if options are invalid, if api return an error or in case of network error.
+    pub async fn timeline_with_precedence(
+        &self,
+        location: location::Location,
+        variables: Vec<String>,
+        past_days: u16,
+        forecast_days: u8,
+        precedence: Precedence,
+    ) -> Result<Timeline, Box<dyn Error>> {
+        let today = chrono::Utc::now().date_naive();
+        let start = today - chrono::Duration::days(i64::from(past_days));
+        let end = today + chrono::Duration::days(i64::from(forecast_days));
+        let archive_end = today - chrono::Duration::days(forecast::ARCHIVE_DELAY_DAYS);
+
+        let opts = forecast::Options {
+            location,
+            hourly: variables,
+            time_zone: Some("UTC".into()),
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::default();
+        let mut archive = Vec::new();
+        let mut lag_days = i64::from(past_days);
+
+        if start <= archive_end {
+            let res = self
+                .archive(forecast::Options {
+                    start_date: Some(start),
+                    end_date: Some(archive_end),
+                    ..opts.clone()
+                })
+                .await?;
+            archive = res.hourly.unwrap_or_default();
+            // Overlap the last archived day so precedence applies to it
+            lag_days = forecast::ARCHIVE_DELAY_DAYS + 1;
+        }
+
+        let res = self
+            .forecast(forecast::Options {
+                past_days: Some(u8::try_from(lag_days)?.min(forecast::MAX_PAST_DAYS)),
+                forecast_days: Some(forecast_days),
+                ..opts
+            })
+            .await?;
+        timeline.metadata = res.metadata;
+
+        timeline.hourly = merge(archive, res.hourly.unwrap_or_default(), precedence)
+            .into_iter()
+            .filter(|rec| {
+                let date = rec.record.datetime.date();
+                date >= start && date < end
+            })
+            .collect();
+
+        Ok(timeline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use forecast::{ForecastResultHourly, ForecastResultItem};
+
+    fn records(start: u32, end: u32, value: Option<f64>) -> Vec<ForecastResultHourly> {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        (start..end)
+            .map(|hour| ForecastResultHourly {
+                datetime: date.and_hms_opt(hour, 0, 0).unwrap(),
+                values: [(
+                    "temperature_2m".to_string(),
+                    ForecastResultItem {
+                        unit: None,
+                        value: value.map_or(serde_json::Value::Null, Into::into),
+                    },
+                )]
+                .into(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn merge_by_precedence() {
+        let mut archive = records(0, 6, Some(1.0));
+        archive.extend(records(6, 8, None));
+        let forecast = records(4, 12, Some(2.0));
+
+        let merged = merge(archive.clone(), forecast.clone(), Precedence::Archive);
+        assert_eq!(merged.len(), 12);
+        let sources: Vec<Source> = merged.iter().map(|rec| rec.source).collect();
+        assert_eq!(sources[..6], [Source::Archive; 6]);
+        assert_eq!(sources[6..], [Source::Forecast; 6]);
+        assert_eq!(merged[5].record.value_f64("temperature_2m"), Some(1.0));
+
+        let merged = merge(archive, forecast, Precedence::Forecast);
+        assert_eq!(merged.len(), 12);
+        assert_eq!(merged[4].source, Source::Forecast);
+        assert_eq!(merged[3].source, Source::Archive);
+    }
+}
@@ -0,0 +1,402 @@
+//! Unit-aware values and client-side unit conversion.
+//!
+//! Units returned by the API (`°C`, `km/h`, `mm`, ...) are parsed into [`Unit`], results can
+//! then be converted without requesting the data again:
+//!
+//! ```
+//! use open_meteo_rs::forecast::{ForecastResult, WindSpeedUnit};
+//!
+//! let mut result = ForecastResult::default();
+//! result.convert_wind(WindSpeedUnit::Kn);
+//! ```
+
+use crate::errors;
+use crate::forecast::{
+    ForecastResult, ForecastResultItem, PrecipitationUnit, TemperatureUnit, WindSpeedUnit,
+};
+use std::fmt::Display;
+
+const FEET_PER_METER: f64 = 3.280_839_895;
+const METERS_PER_INCH: f64 = 0.0254;
+const METERS_PER_MILE: f64 = 1_609.344;
+const HPA_PER_INHG: f64 = 33.863_886_67;
+const HPA_PER_MMHG: f64 = 1.333_223_874;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PressureUnit {
+    Hpa,
+    Kpa,
+    Pa,
+    InHg,
+    MmHg,
+}
+
+/// Radiation flux (W/m²) and energy (MJ/m², Wh/m²) units
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RadiationUnit {
+    WattPerSquareMeter,
+    KilowattPerSquareMeter,
+    MegajoulePerSquareMeter,
+    WattHourPerSquareMeter,
+    KilowattHourPerSquareMeter,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LengthUnit {
+    Millimeter,
+    Centimeter,
+    Meter,
+    Kilometer,
+    Inch,
+    Foot,
+    Mile,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Temperature(TemperatureUnit),
+    Speed(WindSpeedUnit),
+    Precipitation(PrecipitationUnit),
+    Pressure(PressureUnit),
+    Radiation(RadiationUnit),
+    Length(LengthUnit),
+}
+
+impl Unit {
+    /// Convert `value` expressed in this unit to `to`, `None` if the units are not compatible
+    #[must_use]
+    pub fn convert(&self, value: f64, to: Unit) -> Option<f64> {
+        match (self, to) {
+            (Self::Temperature(from), Self::Temperature(to)) => {
+                Some(celsius_to(to, celsius_from(*from, value)))
+            }
+            (Self::Pressure(from), Self::Pressure(to)) => {
+                Some(value * hpa_factor(*from) / hpa_factor(to))
+            }
+            (Self::Radiation(from), Self::Radiation(to)) => {
+                let (from_flux, from_factor) = radiation_factor(*from);
+                let (to_flux, to_factor) = radiation_factor(to);
+                (from_flux == to_flux).then(|| value * from_factor / to_factor)
+            }
+            (Self::Speed(from), Self::Speed(to)) => Some(value * ms_factor(*from) / ms_factor(to)),
+            (from, to) => Some(value * from.meter_factor()? / to.meter_factor()?),
+        }
+    }
+
+    /// Size of the unit in meters, for length and precipitation units
+    fn meter_factor(self) -> Option<f64> {
+        match self {
+            Self::Precipitation(PrecipitationUnit::Millimeters)
+            | Self::Length(LengthUnit::Millimeter) => Some(0.001),
+            Self::Precipitation(PrecipitationUnit::Inches) | Self::Length(LengthUnit::Inch) => {
+                Some(METERS_PER_INCH)
+            }
+            Self::Length(LengthUnit::Centimeter) => Some(0.01),
+            Self::Length(LengthUnit::Meter) => Some(1.0),
+            Self::Length(LengthUnit::Kilometer) => Some(1_000.0),
+            Self::Length(LengthUnit::Foot) => Some(1.0 / FEET_PER_METER),
+            Self::Length(LengthUnit::Mile) => Some(METERS_PER_MILE),
+            _ => None,
+        }
+    }
+}
+
+fn celsius_from(unit: TemperatureUnit, value: f64) -> f64 {
+    match unit {
+        TemperatureUnit::Celsius => value,
+        TemperatureUnit::Fahrenheit => (value - 32.0) * 5.0 / 9.0,
+    }
+}
+
+fn celsius_to(unit: TemperatureUnit, value: f64) -> f64 {
+    match unit {
+        TemperatureUnit::Celsius => value,
+        TemperatureUnit::Fahrenheit => value * 9.0 / 5.0 + 32.0,
+    }
+}
+
+fn ms_factor(unit: WindSpeedUnit) -> f64 {
+    match unit {
+        WindSpeedUnit::Ms => 1.0,
+        WindSpeedUnit::Kmh => 1.0 / 3.6,
+        WindSpeedUnit::Mph => METERS_PER_MILE / 3_600.0,
+        WindSpeedUnit::Kn => 1_852.0 / 3_600.0,
+    }
+}
+
+fn hpa_factor(unit: PressureUnit) -> f64 {
+    match unit {
+        PressureUnit::Hpa => 1.0,
+        PressureUnit::Kpa => 10.0,
+        PressureUnit::Pa => 0.01,
+        PressureUnit::InHg => HPA_PER_INHG,
+        PressureUnit::MmHg => HPA_PER_MMHG,
+    }
+}
+
+/// Whether the unit is a flux, and its size in W/m² or J/m²
+fn radiation_factor(unit: RadiationUnit) -> (bool, f64) {
+    match unit {
+        RadiationUnit::WattPerSquareMeter => (true, 1.0),
+        RadiationUnit::KilowattPerSquareMeter => (true, 1_000.0),
+        RadiationUnit::MegajoulePerSquareMeter => (false, 1_000_000.0),
+        RadiationUnit::WattHourPerSquareMeter => (false, 3_600.0),
+        RadiationUnit::KilowattHourPerSquareMeter => (false, 3_600_000.0),
+    }
+}
+
+impl Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Temperature(TemperatureUnit::Celsius) => write!(f, "°C"),
+            Self::Temperature(TemperatureUnit::Fahrenheit) => write!(f, "°F"),
+            Self::Speed(WindSpeedUnit::Kmh) => write!(f, "km/h"),
+            Self::Speed(WindSpeedUnit::Ms) => write!(f, "m/s"),
+            Self::Speed(WindSpeedUnit::Mph) => write!(f, "mp/h"),
+            Self::Speed(WindSpeedUnit::Kn) => write!(f, "kn"),
+            Self::Precipitation(PrecipitationUnit::Millimeters)
+            | Self::Length(LengthUnit::Millimeter) => write!(f, "mm"),
+            Self::Precipitation(PrecipitationUnit::Inches) | Self::Length(LengthUnit::Inch) => {
+                write!(f, "inch")
+            }
+            Self::Pressure(PressureUnit::Hpa) => write!(f, "hPa"),
+            Self::Pressure(PressureUnit::Kpa) => write!(f, "kPa"),
+            Self::Pressure(PressureUnit::Pa) => write!(f, "Pa"),
+            Self::Pressure(PressureUnit::InHg) => write!(f, "inHg"),
+            Self::Pressure(PressureUnit::MmHg) => write!(f, "mmHg"),
+            Self::Radiation(RadiationUnit::WattPerSquareMeter) => write!(f, "W/m²"),
+            Self::Radiation(RadiationUnit::KilowattPerSquareMeter) => write!(f, "kW/m²"),
+            Self::Radiation(RadiationUnit::MegajoulePerSquareMeter) => write!(f, "MJ/m²"),
+            Self::Radiation(RadiationUnit::WattHourPerSquareMeter) => write!(f, "Wh/m²"),
+            Self::Radiation(RadiationUnit::KilowattHourPerSquareMeter) => write!(f, "kWh/m²"),
+            Self::Length(LengthUnit::Centimeter) => write!(f, "cm"),
+            Self::Length(LengthUnit::Meter) => write!(f, "m"),
+            Self::Length(LengthUnit::Kilometer) => write!(f, "km"),
+            Self::Length(LengthUnit::Foot) => write!(f, "ft"),
+            Self::Length(LengthUnit::Mile) => write!(f, "mi"),
+        }
+    }
+}
+
+impl From<Unit> for String {
+    fn from(value: Unit) -> Self {
+        value.to_string()
+    }
+}
+
+impl TryFrom<&str> for Unit {
+    type Error = errors::ConversionError;
+
+    /// Parse a unit as returned by the API, `mm` and `inch` are read as precipitation units.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "°C" => Ok(Self::Temperature(TemperatureUnit::Celsius)),
+            "°F" => Ok(Self::Temperature(TemperatureUnit::Fahrenheit)),
+            "km/h" => Ok(Self::Speed(WindSpeedUnit::Kmh)),
+            "m/s" => Ok(Self::Speed(WindSpeedUnit::Ms)),
+            "mp/h" | "mph" => Ok(Self::Speed(WindSpeedUnit::Mph)),
+            "kn" | "kt" => Ok(Self::Speed(WindSpeedUnit::Kn)),
+            "mm" => Ok(Self::Precipitation(PrecipitationUnit::Millimeters)),
+            "inch" | "in" => Ok(Self::Precipitation(PrecipitationUnit::Inches)),
+            "hPa" => Ok(Self::Pressure(PressureUnit::Hpa)),
+            "kPa" => Ok(Self::Pressure(PressureUnit::Kpa)),
+            "Pa" => Ok(Self::Pressure(PressureUnit::Pa)),
+            "inHg" => Ok(Self::Pressure(PressureUnit::InHg)),
+            "mmHg" => Ok(Self::Pressure(PressureUnit::MmHg)),
+            "W/m²" => Ok(Self::Radiation(RadiationUnit::WattPerSquareMeter)),
+            "kW/m²" => Ok(Self::Radiation(RadiationUnit::KilowattPerSquareMeter)),
+            "MJ/m²" => Ok(Self::Radiation(RadiationUnit::MegajoulePerSquareMeter)),
+            "Wh/m²" => Ok(Self::Radiation(RadiationUnit::WattHourPerSquareMeter)),
+            "kWh/m²" => Ok(Self::Radiation(RadiationUnit::KilowattHourPerSquareMeter)),
+            "cm" => Ok(Self::Length(LengthUnit::Centimeter)),
+            "m" => Ok(Self::Length(LengthUnit::Meter)),
+            "km" => Ok(Self::Length(LengthUnit::Kilometer)),
+            "ft" => Ok(Self::Length(LengthUnit::Foot)),
+            "mi" => Ok(Self::Length(LengthUnit::Mile)),
+            _ => Err(errors::ConversionError::InvalidUnit {
+                unit: value.to_string(),
+            }),
+        }
+    }
+}
+
+/// A numeric value with its unit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quantity {
+    pub value: f64,
+    pub unit: Unit,
+}
+
+impl Quantity {
+    /// Convert to another unit, `None` if the units are not compatible
+    #[must_use]
+    pub fn convert(&self, to: Unit) -> Option<Quantity> {
+        Some(Quantity {
+            value: self.unit.convert(self.value, to)?,
+            unit: to,
+        })
+    }
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.value, self.unit)
+    }
+}
+
+impl ForecastResultItem {
+    /// Typed value, `None` if the value is missing or the unit is unknown
+    #[must_use]
+    pub fn quantity(&self) -> Option<Quantity> {
+        Some(Quantity {
+            value: self.as_f64()?,
+            unit: self.unit.as_deref()?.try_into().ok()?,
+        })
+    }
+
+    /// Convert the item in place if its unit matches `filter` and is compatible with `to`.
+    /// Missing values are left untouched but still get the new unit.
+    fn convert_if(&mut self, to: Unit, filter: fn(&Unit) -> bool) {
+        let Some(unit) = self
+            .unit
+            .as_deref()
+            .and_then(|u| Unit::try_from(u).ok())
+            .filter(filter)
+        else {
+            return;
+        };
+
+        if unit.convert(0.0, to).is_none() {
+            return;
+        }
+
+        if let Some(v) = self.as_f64().and_then(|v| unit.convert(v, to)) {
+            self.value = v.into();
+        }
+        self.unit = Some(to.to_string());
+    }
+}
+
+impl ForecastResult {
+    /// Convert every temperature value
+    pub fn convert_temperature(&mut self, to: TemperatureUnit) {
+        self.convert_items(Unit::Temperature(to), |u| matches!(u, Unit::Temperature(_)));
+    }
+
+    /// Convert every speed value (wind speed and gusts)
+    pub fn convert_wind(&mut self, to: WindSpeedUnit) {
+        self.convert_items(Unit::Speed(to), |u| matches!(u, Unit::Speed(_)));
+    }
+
+    /// Convert every precipitation value (`mm` or `inch`). Snowfall is returned in `cm` and is
+    /// converted with [`ForecastResult::convert_length`].
+    pub fn convert_precipitation(&mut self, to: PrecipitationUnit) {
+        self.convert_items(Unit::Precipitation(to), |u| {
+            matches!(u, Unit::Precipitation(_))
+        });
+    }
+
+    /// Convert every pressure value
+    pub fn convert_pressure(&mut self, to: PressureUnit) {
+        self.convert_items(Unit::Pressure(to), |u| matches!(u, Unit::Pressure(_)));
+    }
+
+    /// Convert every radiation value with a compatible unit (flux to flux, energy to energy)
+    pub fn convert_radiation(&mut self, to: RadiationUnit) {
+        self.convert_items(Unit::Radiation(to), |u| matches!(u, Unit::Radiation(_)));
+    }
+
+    /// Convert every length value (snowfall, snow depth, visibility...), precipitation values
+    /// are left untouched
+    pub fn convert_length(&mut self, to: LengthUnit) {
+        self.convert_items(Unit::Length(to), |u| matches!(u, Unit::Length(_)));
+    }
+
+    fn convert_items(&mut self, to: Unit, filter: fn(&Unit) -> bool) {
+        let current = self
+            .current
+            .iter_mut()
+            .flat_map(|rec| rec.values.values_mut());
+        let minutely_15 = self
+            .minutely_15
+            .iter_mut()
+            .flatten()
+            .flat_map(|rec| rec.values.values_mut());
+        let hourly = self
+            .hourly
+            .iter_mut()
+            .flatten()
+            .flat_map(|rec| rec.values.values_mut());
+        let daily = self
+            .daily
+            .iter_mut()
+            .flatten()
+            .flat_map(|rec| rec.values.values_mut());
+
+        for item in current.chain(minutely_15).chain(hourly).chain(daily) {
+            item.convert_if(to, filter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultHourly;
+
+    #[test]
+    fn convert_quantities() {
+        let q = Quantity {
+            value: 100.0,
+            unit: "°C".try_into().unwrap(),
+        };
+        let f = q
+            .convert(Unit::Temperature(TemperatureUnit::Fahrenheit))
+            .unwrap();
+        assert!((f.value - 212.0).abs() < 1e-9);
+
+        let kmh = Unit::try_from("km/h").unwrap();
+        let kn = kmh.convert(1.852, Unit::Speed(WindSpeedUnit::Kn)).unwrap();
+        assert!((kn - 1.0).abs() < 1e-9);
+
+        let mj = Unit::Radiation(RadiationUnit::KilowattHourPerSquareMeter)
+            .convert(1.0, Unit::Radiation(RadiationUnit::MegajoulePerSquareMeter))
+            .unwrap();
+        assert!((mj - 3.6).abs() < 1e-9);
+        assert!(Unit::Radiation(RadiationUnit::WattPerSquareMeter)
+            .convert(1.0, Unit::Radiation(RadiationUnit::MegajoulePerSquareMeter))
+            .is_none());
+        assert!(kmh.convert(1.0, Unit::Length(LengthUnit::Meter)).is_none());
+    }
+
+    #[test]
+    fn convert_result_in_place() {
+        let mut rec = ForecastResultHourly::default();
+        rec.values.insert(
+            "wind_speed_10m".into(),
+            ForecastResultItem {
+                unit: Some("m/s".into()),
+                value: 10.0.into(),
+            },
+        );
+        rec.values.insert(
+            "temperature_2m".into(),
+            ForecastResultItem {
+                unit: Some("°C".into()),
+                value: 20.0.into(),
+            },
+        );
+        let mut result = ForecastResult {
+            hourly: Some(vec![rec]),
+            ..Default::default()
+        };
+
+        result.convert_wind(WindSpeedUnit::Kmh);
+
+        let rec = &result.hourly.unwrap()[0];
+        let wind = &rec.values["wind_speed_10m"];
+        assert_eq!(wind.unit.as_deref(), Some("km/h"));
+        assert!((wind.as_f64().unwrap() - 36.0).abs() < 1e-9);
+        assert_eq!(rec.value_f64("temperature_2m"), Some(20.0));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{errors::ValidationError, location};
+use std::fmt::Display;
+
+pub(crate) fn check_location(location: &location::Location) -> Result<(), ValidationError> {
+    check_range("latitude", location.lat, -90.0, 90.0)?;
+    check_range("longitude", location.lng, -180.0, 180.0)
+}
+
+pub(crate) fn check_range<T: PartialOrd + Display + Copy>(
+    name: &str,
+    value: T,
+    min: T,
+    max: T,
+) -> Result<(), ValidationError> {
+    if value >= min && value <= max {
+        return Ok(());
+    }
+
+    Err(ValidationError::OutOfRange {
+        name: name.to_string(),
+        value: value.to_string(),
+        min: min.to_string(),
+        max: max.to_string(),
+    })
+}
+
+pub(crate) fn check_optional_range<T: PartialOrd + Display + Copy>(
+    name: &str,
+    value: Option<T>,
+    min: T,
+    max: T,
+) -> Result<(), ValidationError> {
+    match value {
+        Some(value) => check_range(name, value, min, max),
+        None => Ok(()),
+    }
+}
+
+/// Fail if both parameters are set
+pub(crate) fn check_exclusive(
+    (first, first_set): (&str, bool),
+    (second, second_set): (&str, bool),
+) -> Result<(), ValidationError> {
+    if first_set && second_set {
+        return Err(ValidationError::MutuallyExclusive {
+            first: first.to_string(),
+            second: second.to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Fail if only one of the parameters is set
+pub(crate) fn check_together(
+    (first, first_set): (&str, bool),
+    (second, second_set): (&str, bool),
+) -> Result<(), ValidationError> {
+    match (first_set, second_set) {
+        (true, false) => Err(ValidationError::MissingParameter {
+            name: first.to_string(),
+            required: second.to_string(),
+        }),
+        (false, true) => Err(ValidationError::MissingParameter {
+            name: second.to_string(),
+            required: first.to_string(),
+        }),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn check_dates(
+    start_date: Option<chrono::NaiveDate>,
+    end_date: Option<chrono::NaiveDate>,
+) -> Result<(), ValidationError> {
+    check_together(
+        ("start_date", start_date.is_some()),
+        ("end_date", end_date.is_some()),
+    )?;
+
+    if let (Some(start_date), Some(end_date)) = (start_date, end_date) {
+        if start_date > end_date {
+            return Err(ValidationError::InvalidDateRange {
+                start_date,
+                end_date,
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Check a `start_hour`/`end_hour` style pair of datetimes
+pub(crate) fn check_hours(
+    (start_name, start_hour): (&str, Option<chrono::NaiveDateTime>),
+    (end_name, end_hour): (&str, Option<chrono::NaiveDateTime>),
+) -> Result<(), ValidationError> {
+    check_together(
+        (start_name, start_hour.is_some()),
+        (end_name, end_hour.is_some()),
+    )?;
+
+    if let (Some(start_hour), Some(end_hour)) = (start_hour, end_hour) {
+        if start_hour > end_hour {
+            return Err(ValidationError::InvalidHourRange {
+                start_name: start_name.to_string(),
+                start_hour,
+                end_name: end_name.to_string(),
+                end_hour,
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,483 @@
+//! WMO weather interpretation codes, as returned by the `weather_code` variable.
+
+use crate::errors;
+use crate::forecast::{ForecastResultDaily, ForecastResultHourly};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+pub(crate) const WEATHER_CODE_VARIABLES: [&str; 2] = ["weather_code", "weathercode"];
+
+/// WMO weather codes emitted by Open-Meteo.
+///
+/// Codes are ordered by severity, so the most severe code of a period is its `max()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u8", into = "u8")]
+#[repr(u8)]
+pub enum WeatherCode {
+    ClearSky = 0,
+    MainlyClear = 1,
+    PartlyCloudy = 2,
+    Overcast = 3,
+    Fog = 45,
+    DepositingRimeFog = 48,
+    DrizzleLight = 51,
+    DrizzleModerate = 53,
+    DrizzleDense = 55,
+    FreezingDrizzleLight = 56,
+    FreezingDrizzleDense = 57,
+    RainSlight = 61,
+    RainModerate = 63,
+    RainHeavy = 65,
+    FreezingRainLight = 66,
+    FreezingRainHeavy = 67,
+    SnowFallSlight = 71,
+    SnowFallModerate = 73,
+    SnowFallHeavy = 75,
+    SnowGrains = 77,
+    RainShowersSlight = 80,
+    RainShowersModerate = 81,
+    RainShowersViolent = 82,
+    SnowShowersSlight = 85,
+    SnowShowersHeavy = 86,
+    Thunderstorm = 95,
+    ThunderstormSlightHail = 96,
+    ThunderstormHeavyHail = 99,
+}
+
+/// Kind of precipitation associated with a weather code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PrecipitationType {
+    None,
+    Drizzle,
+    FreezingDrizzle,
+    Rain,
+    FreezingRain,
+    Snow,
+    Hail,
+}
+
+/// Languages available for weather code descriptions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Language {
+    #[default]
+    En,
+    Fr,
+    De,
+    Es,
+}
+
+impl WeatherCode {
+    /// WMO code
+    #[must_use]
+    pub fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// Severity from 0 (clear sky) to 10 (thunderstorm with heavy hail)
+    #[must_use]
+    pub fn severity(self) -> u8 {
+        match self {
+            Self::ClearSky | Self::MainlyClear => 0,
+            Self::PartlyCloudy | Self::Overcast => 1,
+            Self::Fog | Self::DrizzleLight => 2,
+            Self::DepositingRimeFog
+            | Self::DrizzleModerate
+            | Self::RainSlight
+            | Self::RainShowersSlight => 3,
+            Self::DrizzleDense | Self::RainModerate | Self::SnowFallSlight | Self::SnowGrains => 4,
+            Self::FreezingDrizzleLight
+            | Self::SnowFallModerate
+            | Self::RainShowersModerate
+            | Self::SnowShowersSlight => 5,
+            Self::FreezingDrizzleDense | Self::RainHeavy | Self::FreezingRainLight => 6,
+            Self::FreezingRainHeavy
+            | Self::SnowFallHeavy
+            | Self::RainShowersViolent
+            | Self::SnowShowersHeavy => 7,
+            Self::Thunderstorm => 8,
+            Self::ThunderstormSlightHail => 9,
+            Self::ThunderstormHeavyHail => 10,
+        }
+    }
+
+    #[must_use]
+    pub fn precipitation_type(self) -> PrecipitationType {
+        match self {
+            Self::ClearSky
+            | Self::MainlyClear
+            | Self::PartlyCloudy
+            | Self::Overcast
+            | Self::Fog
+            | Self::DepositingRimeFog => PrecipitationType::None,
+            Self::DrizzleLight | Self::DrizzleModerate | Self::DrizzleDense => {
+                PrecipitationType::Drizzle
+            }
+            Self::FreezingDrizzleLight | Self::FreezingDrizzleDense => {
+                PrecipitationType::FreezingDrizzle
+            }
+            Self::RainSlight
+            | Self::RainModerate
+            | Self::RainHeavy
+            | Self::RainShowersSlight
+            | Self::RainShowersModerate
+            | Self::RainShowersViolent
+            | Self::Thunderstorm => PrecipitationType::Rain,
+            Self::FreezingRainLight | Self::FreezingRainHeavy => PrecipitationType::FreezingRain,
+            Self::SnowFallSlight
+            | Self::SnowFallModerate
+            | Self::SnowFallHeavy
+            | Self::SnowGrains
+            | Self::SnowShowersSlight
+            | Self::SnowShowersHeavy => PrecipitationType::Snow,
+            Self::ThunderstormSlightHail | Self::ThunderstormHeavyHail => PrecipitationType::Hail,
+        }
+    }
+
+    #[must_use]
+    pub fn is_thunderstorm(self) -> bool {
+        matches!(
+            self,
+            Self::Thunderstorm | Self::ThunderstormSlightHail | Self::ThunderstormHeavyHail
+        )
+    }
+
+    /// Icon identifier, following the usual `clear-day` / `clear-night` naming
+    #[must_use]
+    pub fn icon(self, is_day: bool) -> &'static str {
+        match (self, is_day) {
+            (Self::ClearSky, true) => "clear-day",
+            (Self::ClearSky, false) => "clear-night",
+            (Self::MainlyClear | Self::PartlyCloudy, true) => "partly-cloudy-day",
+            (Self::MainlyClear | Self::PartlyCloudy, false) => "partly-cloudy-night",
+            (Self::Overcast, _) => "overcast",
+            (Self::Fog | Self::DepositingRimeFog, true) => "fog-day",
+            (Self::Fog | Self::DepositingRimeFog, false) => "fog-night",
+            (Self::DrizzleLight | Self::DrizzleModerate | Self::DrizzleDense, _) => "drizzle",
+            (
+                Self::FreezingDrizzleLight
+                | Self::FreezingDrizzleDense
+                | Self::FreezingRainLight
+                | Self::FreezingRainHeavy,
+                _,
+            ) => "sleet",
+            (Self::RainSlight | Self::RainModerate | Self::RainHeavy, _) => "rain",
+            (
+                Self::RainShowersSlight | Self::RainShowersModerate | Self::RainShowersViolent,
+                true,
+            ) => "partly-cloudy-day-rain",
+            (
+                Self::RainShowersSlight | Self::RainShowersModerate | Self::RainShowersViolent,
+                false,
+            ) => "partly-cloudy-night-rain",
+            (
+                Self::SnowFallSlight
+                | Self::SnowFallModerate
+                | Self::SnowFallHeavy
+                | Self::SnowGrains,
+                _,
+            ) => "snow",
+            (Self::SnowShowersSlight | Self::SnowShowersHeavy, true) => "partly-cloudy-day-snow",
+            (Self::SnowShowersSlight | Self::SnowShowersHeavy, false) => "partly-cloudy-night-snow",
+            (Self::Thunderstorm, true) => "thunderstorms-day",
+            (Self::Thunderstorm, false) => "thunderstorms-night",
+            (Self::ThunderstormSlightHail | Self::ThunderstormHeavyHail, _) => "thunderstorms-hail",
+        }
+    }
+
+    /// Human readable description
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn description(self, language: Language) -> &'static str {
+        match language {
+            Language::En => match self {
+                Self::ClearSky => "Clear sky",
+                Self::MainlyClear => "Mainly clear",
+                Self::PartlyCloudy => "Partly cloudy",
+                Self::Overcast => "Overcast",
+                Self::Fog => "Fog",
+                Self::DepositingRimeFog => "Depositing rime fog",
+                Self::DrizzleLight => "Light drizzle",
+                Self::DrizzleModerate => "Moderate drizzle",
+                Self::DrizzleDense => "Dense drizzle",
+                Self::FreezingDrizzleLight => "Light freezing drizzle",
+                Self::FreezingDrizzleDense => "Dense freezing drizzle",
+                Self::RainSlight => "Slight rain",
+                Self::RainModerate => "Moderate rain",
+                Self::RainHeavy => "Heavy rain",
+                Self::FreezingRainLight => "Light freezing rain",
+                Self::FreezingRainHeavy => "Heavy freezing rain",
+                Self::SnowFallSlight => "Slight snow fall",
+                Self::SnowFallModerate => "Moderate snow fall",
+                Self::SnowFallHeavy => "Heavy snow fall",
+                Self::SnowGrains => "Snow grains",
+                Self::RainShowersSlight => "Slight rain showers",
+                Self::RainShowersModerate => "Moderate rain showers",
+                Self::RainShowersViolent => "Violent rain showers",
+                Self::SnowShowersSlight => "Slight snow showers",
+                Self::SnowShowersHeavy => "Heavy snow showers",
+                Self::Thunderstorm => "Thunderstorm",
+                Self::ThunderstormSlightHail => "Thunderstorm with slight hail",
+                Self::ThunderstormHeavyHail => "Thunderstorm with heavy hail",
+            },
+            Language::Fr => match self {
+                Self::ClearSky => "Ciel dégagé",
+                Self::MainlyClear => "Plutôt dégagé",
+                Self::PartlyCloudy => "Partiellement nuageux",
+                Self::Overcast => "Couvert",
+                Self::Fog => "Brouillard",
+                Self::DepositingRimeFog => "Brouillard givrant",
+                Self::DrizzleLight => "Bruine légère",
+                Self::DrizzleModerate => "Bruine modérée",
+                Self::DrizzleDense => "Bruine dense",
+                Self::FreezingDrizzleLight => "Bruine verglaçante légère",
+                Self::FreezingDrizzleDense => "Bruine verglaçante dense",
+                Self::RainSlight => "Pluie faible",
+                Self::RainModerate => "Pluie modérée",
+                Self::RainHeavy => "Pluie forte",
+                Self::FreezingRainLight => "Pluie verglaçante faible",
+                Self::FreezingRainHeavy => "Pluie verglaçante forte",
+                Self::SnowFallSlight => "Chute de neige faible",
+                Self::SnowFallModerate => "Chute de neige modérée",
+                Self::SnowFallHeavy => "Chute de neige forte",
+                Self::SnowGrains => "Neige en grains",
+                Self::RainShowersSlight => "Averses de pluie faibles",
+                Self::RainShowersModerate => "Averses de pluie modérées",
+                Self::RainShowersViolent => "Averses de pluie violentes",
+                Self::SnowShowersSlight => "Averses de neige faibles",
+                Self::SnowShowersHeavy => "Averses de neige fortes",
+                Self::Thunderstorm => "Orage",
+                Self::ThunderstormSlightHail => "Orage avec grêle faible",
+                Self::ThunderstormHeavyHail => "Orage avec grêle forte",
+            },
+            Language::De => match self {
+                Self::ClearSky => "Klarer Himmel",
+                Self::MainlyClear => "Überwiegend klar",
+                Self::PartlyCloudy => "Teilweise bewölkt",
+                Self::Overcast => "Bedeckt",
+                Self::Fog => "Nebel",
+                Self::DepositingRimeFog => "Reifnebel",
+                Self::DrizzleLight => "Leichter Nieselregen",
+                Self::DrizzleModerate => "Mäßiger Nieselregen",
+                Self::DrizzleDense => "Starker Nieselregen",
+                Self::FreezingDrizzleLight => "Leichter gefrierender Nieselregen",
+                Self::FreezingDrizzleDense => "Starker gefrierender Nieselregen",
+                Self::RainSlight => "Leichter Regen",
+                Self::RainModerate => "Mäßiger Regen",
+                Self::RainHeavy => "Starker Regen",
+                Self::FreezingRainLight => "Leichter gefrierender Regen",
+                Self::FreezingRainHeavy => "Starker gefrierender Regen",
+                Self::SnowFallSlight => "Leichter Schneefall",
+                Self::SnowFallModerate => "Mäßiger Schneefall",
+                Self::SnowFallHeavy => "Starker Schneefall",
+                Self::SnowGrains => "Schneegriesel",
+                Self::RainShowersSlight => "Leichte Regenschauer",
+                Self::RainShowersModerate => "Mäßige Regenschauer",
+                Self::RainShowersViolent => "Heftige Regenschauer",
+                Self::SnowShowersSlight => "Leichte Schneeschauer",
+                Self::SnowShowersHeavy => "Starke Schneeschauer",
+                Self::Thunderstorm => "Gewitter",
+                Self::ThunderstormSlightHail => "Gewitter mit leichtem Hagel",
+                Self::ThunderstormHeavyHail => "Gewitter mit starkem Hagel",
+            },
+            Language::Es => match self {
+                Self::ClearSky => "Cielo despejado",
+                Self::MainlyClear => "Mayormente despejado",
+                Self::PartlyCloudy => "Parcialmente nublado",
+                Self::Overcast => "Cubierto",
+                Self::Fog => "Niebla",
+                Self::DepositingRimeFog => "Niebla engelante",
+                Self::DrizzleLight => "Llovizna ligera",
+                Self::DrizzleModerate => "Llovizna moderada",
+                Self::DrizzleDense => "Llovizna densa",
+                Self::FreezingDrizzleLight => "Llovizna helada ligera",
+                Self::FreezingDrizzleDense => "Llovizna helada densa",
+                Self::RainSlight => "Lluvia ligera",
+                Self::RainModerate => "Lluvia moderada",
+                Self::RainHeavy => "Lluvia intensa",
+                Self::FreezingRainLight => "Lluvia helada ligera",
+                Self::FreezingRainHeavy => "Lluvia helada intensa",
+                Self::SnowFallSlight => "Nevada ligera",
+                Self::SnowFallModerate => "Nevada moderada",
+                Self::SnowFallHeavy => "Nevada intensa",
+                Self::SnowGrains => "Cinarra",
+                Self::RainShowersSlight => "Chubascos ligeros",
+                Self::RainShowersModerate => "Chubascos moderados",
+                Self::RainShowersViolent => "Chubascos violentos",
+                Self::SnowShowersSlight => "Chubascos de nieve ligeros",
+                Self::SnowShowersHeavy => "Chubascos de nieve intensos",
+                Self::Thunderstorm => "Tormenta",
+                Self::ThunderstormSlightHail => "Tormenta con granizo ligero",
+                Self::ThunderstormHeavyHail => "Tormenta con granizo fuerte",
+            },
+        }
+    }
+}
+
+impl PartialOrd for WeatherCode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for WeatherCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.severity(), self.code()).cmp(&(other.severity(), other.code()))
+    }
+}
+
+impl Display for WeatherCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.description(Language::En))
+    }
+}
+
+impl From<WeatherCode> for u8 {
+    fn from(value: WeatherCode) -> Self {
+        value.code()
+    }
+}
+
+impl TryFrom<u8> for WeatherCode {
+    type Error = errors::ConversionError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::ClearSky),
+            1 => Ok(Self::MainlyClear),
+            2 => Ok(Self::PartlyCloudy),
+            3 => Ok(Self::Overcast),
+            45 => Ok(Self::Fog),
+            48 => Ok(Self::DepositingRimeFog),
+            51 => Ok(Self::DrizzleLight),
+            53 => Ok(Self::DrizzleModerate),
+            55 => Ok(Self::DrizzleDense),
+            56 => Ok(Self::FreezingDrizzleLight),
+            57 => Ok(Self::FreezingDrizzleDense),
+            61 => Ok(Self::RainSlight),
+            63 => Ok(Self::RainModerate),
+            65 => Ok(Self::RainHeavy),
+            66 => Ok(Self::FreezingRainLight),
+            67 => Ok(Self::FreezingRainHeavy),
+            71 => Ok(Self::SnowFallSlight),
+            73 => Ok(Self::SnowFallModerate),
+            75 => Ok(Self::SnowFallHeavy),
+            77 => Ok(Self::SnowGrains),
+            80 => Ok(Self::RainShowersSlight),
+            81 => Ok(Self::RainShowersModerate),
+            82 => Ok(Self::RainShowersViolent),
+            85 => Ok(Self::SnowShowersSlight),
+            86 => Ok(Self::SnowShowersHeavy),
+            95 => Ok(Self::Thunderstorm),
+            96 => Ok(Self::ThunderstormSlightHail),
+            99 => Ok(Self::ThunderstormHeavyHail),
+            _ => Err(errors::ConversionError::InvalidWeatherCode {
+                code: value.to_string(),
+            }),
+        }
+    }
+}
+
+impl TryFrom<&serde_json::Value> for WeatherCode {
+    type Error = errors::ConversionError;
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn try_from(value: &serde_json::Value) -> Result<Self, Self::Error> {
+        // Codes are sometimes encoded as floats (`3.0`)
+        let code = value.as_u64().or_else(|| {
+            value
+                .as_f64()
+                .filter(|v| v.fract() == 0.0 && *v >= 0.0)
+                .map(|v| v as u64)
+        });
+
+        code.and_then(|v| u8::try_from(v).ok())
+            .ok_or_else(|| errors::ConversionError::InvalidWeatherCode {
+                code: value.to_string(),
+            })
+            .and_then(Self::try_from)
+    }
+}
+
+impl ForecastResultHourly {
+    /// Decoded `weather_code` variable
+    #[must_use]
+    pub fn weather_code(&self) -> Option<WeatherCode> {
+        WEATHER_CODE_VARIABLES
+            .iter()
+            .find_map(|k| self.values.get(*k))
+            .and_then(|item| WeatherCode::try_from(&item.value).ok())
+    }
+
+    /// Decoded `is_day` variable
+    #[must_use]
+    pub fn is_day(&self) -> Option<bool> {
+        self.value_f64("is_day").map(|v| v > 0.0)
+    }
+
+    /// Icon for the weather code, using `is_day` when available (day otherwise)
+    #[must_use]
+    pub fn weather_icon(&self) -> Option<&'static str> {
+        Some(self.weather_code()?.icon(self.is_day().unwrap_or(true)))
+    }
+}
+
+impl ForecastResultDaily {
+    /// Decoded `weather_code` variable (most severe code of the day)
+    #[must_use]
+    pub fn weather_code(&self) -> Option<WeatherCode> {
+        WEATHER_CODE_VARIABLES
+            .iter()
+            .find_map(|k| self.values.get(*k))
+            .and_then(|item| WeatherCode::try_from(&item.value).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::forecast::ForecastResultItem;
+
+    #[test]
+    fn decode_weather_code() {
+        let mut rec = ForecastResultHourly::default();
+        rec.values.insert(
+            "weather_code".into(),
+            ForecastResultItem {
+                unit: Some("wmo code".into()),
+                value: 80.into(),
+            },
+        );
+        rec.values.insert(
+            "is_day".into(),
+            ForecastResultItem {
+                unit: None,
+                value: 0.into(),
+            },
+        );
+
+        assert_eq!(rec.weather_code(), Some(WeatherCode::RainShowersSlight));
+        assert_eq!(rec.weather_icon(), Some("partly-cloudy-night-rain"));
+        assert_eq!(
+            WeatherCode::try_from(&serde_json::json!(3.0)).unwrap(),
+            WeatherCode::Overcast
+        );
+        assert!(WeatherCode::try_from(4).is_err());
+    }
+
+    #[test]
+    fn order_by_severity() {
+        let codes = [
+            WeatherCode::Fog,
+            WeatherCode::RainHeavy,
+            WeatherCode::SnowShowersSlight,
+        ];
+        assert_eq!(codes.iter().max(), Some(&WeatherCode::RainHeavy));
+        assert!(WeatherCode::ThunderstormHeavyHail > WeatherCode::Thunderstorm);
+        assert_eq!(
+            WeatherCode::SnowGrains.precipitation_type(),
+            PrecipitationType::Snow
+        );
+        assert_eq!(WeatherCode::Fog.description(Language::Fr), "Brouillard");
+    }
+}